@@ -0,0 +1,45 @@
+mod model;
+mod upstream;
+
+use serde_json::json;
+
+use crate::{error::ApiResult, gql::Operation};
+
+use model::Category;
+pub use model::CategoryTreeResponse;
+use upstream::{CategoryAllListQuery, CategoryNode};
+
+const TREE_OPERATION: Operation = Operation {
+    name: "categoryAllList",
+    query: include_str!("tree.graphql"),
+    akamai: None,
+};
+
+pub async fn tree() -> ApiResult<CategoryTreeResponse> {
+    let response = TREE_OPERATION
+        .fetch(json!({ "categoryID": 0, "type": "tree" }))
+        .await?;
+
+    let results = TREE_OPERATION
+        .parse::<CategoryAllListQuery>(&response)?
+        .category_all_list
+        .categories
+        .into_iter()
+        .map(category)
+        .collect();
+
+    Ok(CategoryTreeResponse {
+        success: true,
+        results,
+    })
+}
+
+fn category(node: CategoryNode) -> Category {
+    Category {
+        id: node.id,
+        name: node.name,
+        url: node.url,
+        icon: node.icon_image_url,
+        children: node.child.into_iter().map(category).collect(),
+    }
+}
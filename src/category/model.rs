@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+/// Body of `GET /categories`.
+#[derive(Serialize)]
+pub struct CategoryTreeResponse {
+    pub success: bool,
+    pub results: Vec<Category>,
+}
+
+#[derive(Serialize)]
+pub struct Category {
+    /// Usable as `/categories/{id}/products`
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    /// Only sent for top level categories, empty otherwise
+    pub icon: String,
+    pub children: Vec<Category>,
+}
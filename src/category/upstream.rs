@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+use crate::de;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryAllListQuery {
+    pub category_all_list: CategoryAllList,
+}
+
+#[derive(Deserialize)]
+pub struct CategoryAllList {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub categories: Vec<CategoryNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryNode {
+    #[serde(deserialize_with = "de::string")]
+    pub id: String,
+    pub name: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub url: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub icon_image_url: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub child: Vec<CategoryNode>,
+}
@@ -0,0 +1,60 @@
+use time::{
+    macros::{format_description, offset},
+    Date, Month, OffsetDateTime, PrimitiveDateTime,
+};
+
+/// Parses an upstream date into Unix seconds. The upstream sends either Unix
+/// seconds, RFC 3339, or a `YYYY-MM-DD hh:mm:ss`, `YYYY-MM-DD` or `YYYY` local
+/// time in Jakarta (UTC+7).
+pub fn unix_timestamp(date: &str) -> Option<i64> {
+    let date = date.trim();
+
+    // A bare year, e.g. the date a shop was opened
+    if date.len() == 4 {
+        let year = date.parse().ok()?;
+
+        return Some(
+            Date::from_calendar_date(year, Month::January, 1)
+                .ok()?
+                .midnight()
+                .assume_offset(offset!(+7))
+                .unix_timestamp(),
+        );
+    }
+
+    if let Ok(timestamp) = date.parse::<i64>() {
+        return Some(timestamp).filter(|v| *v > 0);
+    }
+
+    if let Ok(date) = OffsetDateTime::parse(date, &time::format_description::well_known::Rfc3339) {
+        return Some(date.unix_timestamp());
+    }
+
+    if let Ok(date) = Date::parse(date, format_description!("[year]-[month]-[day]")) {
+        return Some(date.midnight().assume_offset(offset!(+7)).unix_timestamp());
+    }
+
+    PrimitiveDateTime::parse(
+        date,
+        format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
+    )
+    .ok()
+    .map(|v| v.assume_offset(offset!(+7)).unix_timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unix_timestamp;
+
+    #[test]
+    fn parses_upstream_dates() {
+        assert_eq!(unix_timestamp("1700000000"), Some(1_700_000_000));
+        assert_eq!(unix_timestamp("2023-11-14T22:13:20Z"), Some(1_700_000_000));
+        assert_eq!(unix_timestamp("2023-11-15 05:13:20"), Some(1_700_000_000));
+        assert_eq!(unix_timestamp("2023-11-15"), Some(1_699_981_200));
+        assert_eq!(unix_timestamp("2016"), Some(1_451_581_200));
+        assert_eq!(unix_timestamp("0"), None);
+        assert_eq!(unix_timestamp("Okt 2016"), None);
+        assert_eq!(unix_timestamp(""), None);
+    }
+}
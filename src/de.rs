@@ -0,0 +1,99 @@
+//! Deserializers for upstream fields whose JSON type is not consistent
+//! between operations (IDs and counters come as numbers or as strings).
+
+use std::{fmt::Display, str::FromStr};
+
+use serde::{de::Error, Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Accepts a string, a number or `null` (as an empty string).
+pub fn string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::String(value) => Ok(value),
+        Value::Number(value) => Ok(value.to_string()),
+        Value::Null => Ok(String::new()),
+        value => Err(D::Error::custom(format!(
+            "expected a string or a number, found {value}"
+        ))),
+    }
+}
+
+/// Accepts a number, a numeric string or `null` (as the default value).
+pub fn number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Default,
+    T::Err: Display,
+{
+    let value = match Value::deserialize(deserializer)? {
+        // Integral floats such as `15000.0` should still parse as integers
+        Value::Number(value) => match value.as_f64() {
+            Some(float) if value.is_f64() && float.fract() == 0.0 => format!("{float:.0}"),
+            _ => value.to_string(),
+        },
+        Value::String(value) if value.trim().is_empty() => return Ok(T::default()),
+        Value::String(value) => value,
+        Value::Null => return Ok(T::default()),
+        value => {
+            return Err(D::Error::custom(format!(
+                "expected a number, found {value}"
+            )))
+        }
+    };
+
+    value.trim().parse().map_err(D::Error::custom)
+}
+
+/// Accepts a list of strings and/or numbers, `null` being an empty list.
+pub fn strings<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    struct Item(#[serde(deserialize_with = "string")] String);
+
+    Ok(Option::<Vec<Item>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|v| v.0)
+        .collect())
+}
+
+/// Treats `null` like a missing field, falling back to the default value.
+pub fn nullable<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Accepts a boolean, or a `0`/`1` number or string as used by older fields.
+pub fn flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Bool(value) => Ok(value),
+        Value::Number(value) => Ok(value.as_f64().unwrap_or_default() != 0.0),
+        Value::String(value) => Ok(!matches!(value.trim(), "" | "0" | "false")),
+        Value::Null => Ok(false),
+        value => Err(D::Error::custom(format!(
+            "expected a boolean, found {value}"
+        ))),
+    }
+}
+
+/// Accepts a single object, a list of them or `null` (as an empty list).
+pub fn one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+
+    Ok(match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        Some(OneOrMany::Many(values)) => values,
+        Some(OneOrMany::One(value)) => vec![value],
+        None => Vec::new(),
+    })
+}
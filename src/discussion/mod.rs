@@ -0,0 +1,90 @@
+mod model;
+mod options;
+mod upstream;
+
+use serde_json::json;
+
+use crate::{date::unix_timestamp, error::ApiResult, gql::Operation, lookup, query::Query};
+
+pub use model::DiscussionsResponse;
+use model::{Answer, Author, Question};
+use options::DiscussionOptions;
+use upstream::GetDiscussionDataByProductId;
+
+const LIST_OPERATION: Operation = Operation {
+    name: "GetDiscussionDataByProductID",
+    query: include_str!("list.graphql"),
+    akamai: None,
+};
+
+pub async fn discussions(
+    seller: &str,
+    product: &str,
+    query: &Query,
+) -> ApiResult<DiscussionsResponse> {
+    let options = DiscussionOptions::from_query(query)?;
+    let product_id = lookup::product_id(seller, product).await?;
+
+    let response = LIST_OPERATION
+        .fetch(json!({
+            "productID": product_id,
+            "shopID": "",
+            "page": options.page,
+            "limit": options.limit,
+            "sortBy": "",
+            "category": ""
+        }))
+        .await?;
+
+    let data = LIST_OPERATION
+        .parse::<GetDiscussionDataByProductId>(&response)?
+        .discussion_data_by_product_id;
+
+    let results = data
+        .question
+        .into_iter()
+        .map(|question| {
+            let answers = question
+                .answer
+                .into_iter()
+                .filter(|v| !v.content.is_empty())
+                .map(|answer| Answer {
+                    id: answer.answer_id,
+                    content: answer.content,
+                    author: Author {
+                        name: answer.user_name,
+                        avatar: answer.user_thumbnail,
+                    },
+                    is_seller: answer.is_seller,
+                    created_at: unix_timestamp(&answer.create_time),
+                    created_at_text: answer.create_time_formatted,
+                    likes: answer.like_count,
+                })
+                .collect::<Vec<_>>();
+
+            Question {
+                id: question.question_id,
+                content: question.content,
+                asker: Author {
+                    name: question.user_name,
+                    avatar: question.user_thumbnail,
+                },
+                created_at: unix_timestamp(&question.create_time),
+                created_at_text: question.create_time_formatted,
+                total_answers: question.total_answer,
+                latest_answer_by_seller: answers.first().is_some_and(|v| v.is_seller),
+                answers,
+            }
+        })
+        .collect();
+
+    Ok(DiscussionsResponse {
+        success: true,
+        product_id,
+        page: options.page,
+        limit: options.limit,
+        total_data: data.total_question,
+        next_page: data.has_next.then_some(options.page + 1),
+        results,
+    })
+}
@@ -0,0 +1,53 @@
+use serde::Serialize;
+
+/// Body of `GET /lookup/{seller}/{product}/discussions`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscussionsResponse {
+    pub success: bool,
+    pub product_id: String,
+    pub page: u32,
+    pub limit: u32,
+    /// Number of questions across all pages
+    pub total_data: u64,
+    /// Page to request next, `null` on the last page
+    pub next_page: Option<u32>,
+    pub results: Vec<Question>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Question {
+    pub id: String,
+    pub content: String,
+    pub asker: Author,
+    /// Unix timestamp, `null` when the upstream did not send one
+    pub created_at: Option<i64>,
+    /// Upstream wording, e.g. `2 hari lalu`
+    pub created_at_text: String,
+    pub total_answers: u64,
+    /// Whether the latest answer is the seller's. The upstream only sends the
+    /// latest answer, so this is `false` when a buyer replied after the seller.
+    pub latest_answer_by_seller: bool,
+    /// Latest answers, the upstream does not list all of them here
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Answer {
+    pub id: String,
+    pub content: String,
+    pub author: Author,
+    pub is_seller: bool,
+    /// Unix timestamp, `null` when the upstream did not send one
+    pub created_at: Option<i64>,
+    pub created_at_text: String,
+    pub likes: u64,
+}
+
+#[derive(Serialize)]
+pub struct Author {
+    pub name: String,
+    pub avatar: String,
+}
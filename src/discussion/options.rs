@@ -0,0 +1,18 @@
+use crate::{error::ApiResult, query::Query};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 50;
+
+/// Discussion list parameters taken from the request query string.
+pub struct DiscussionOptions {
+    pub page: u32,
+    pub limit: u32,
+}
+
+impl DiscussionOptions {
+    pub fn from_query(query: &Query) -> ApiResult<Self> {
+        let (page, limit) = query.page_and_size("limit", DEFAULT_LIMIT, MAX_LIMIT)?;
+
+        Ok(Self { page, limit })
+    }
+}
@@ -0,0 +1,60 @@
+use serde::Deserialize;
+
+use crate::de;
+
+#[derive(Deserialize)]
+pub struct GetDiscussionDataByProductId {
+    #[serde(rename = "discussionDataByProductID")]
+    pub discussion_data_by_product_id: DiscussionData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscussionData {
+    pub has_next: bool,
+    #[serde(deserialize_with = "de::number")]
+    pub total_question: u64,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub question: Vec<Question>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Question {
+    #[serde(rename = "questionID", deserialize_with = "de::string")]
+    pub question_id: String,
+    pub content: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub user_name: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub user_thumbnail: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub create_time: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub create_time_formatted: String,
+    #[serde(deserialize_with = "de::number")]
+    pub total_answer: u64,
+    /// The upstream sends only the latest answer here, as a single object
+    #[serde(default, deserialize_with = "de::one_or_many")]
+    pub answer: Vec<Answer>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Answer {
+    #[serde(rename = "answerID", deserialize_with = "de::string")]
+    pub answer_id: String,
+    pub content: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub user_name: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub user_thumbnail: String,
+    #[serde(default, deserialize_with = "de::flag")]
+    pub is_seller: bool,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub create_time: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub create_time_formatted: String,
+    #[serde(default, deserialize_with = "de::number")]
+    pub like_count: u64,
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use http_body_util::Full;
+use hyper::{body::Bytes, http::HeaderValue, Response, StatusCode};
+use serde_json::json;
+
+use crate::{Accept, APP_NAME};
+
+/// Everything a route can fail with after the request has been routed.
+#[derive(Debug)]
+pub enum ApiError {
+    /// No route matches the request path
+    RouteNotFound,
+    /// The request parameters are missing or invalid
+    BadRequest(String),
+    /// The upstream has no such resource
+    NotFound(&'static str),
+    /// The upstream answered with something we can't make sense of, the detail
+    /// is logged but never sent to clients
+    UpstreamShape(String),
+    /// The upstream could not be reached or answered with an error status, the
+    /// detail is logged but never sent to clients
+    UpstreamHttp(String),
+    /// The upstream took too long to answer
+    UpstreamTimeout,
+    /// A handler failed or panicked in a way we did not anticipate
+    Internal,
+}
+
+pub type ApiResult<T> = std::result::Result<T, ApiError>;
+
+impl ApiError {
+    /// Wraps any error raised while reading an upstream payload.
+    pub fn shape(err: impl fmt::Display) -> Self {
+        Self::UpstreamShape(err.to_string())
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::RouteNotFound | Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::UpstreamShape(_) | Self::UpstreamHttp(_) => StatusCode::BAD_GATEWAY,
+            Self::UpstreamTimeout => StatusCode::GATEWAY_TIMEOUT,
+            Self::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::RouteNotFound => "route_not_found",
+            Self::BadRequest(_) => "bad_request",
+            Self::NotFound(_) => "not_found",
+            Self::UpstreamShape(_) => "upstream_shape",
+            Self::UpstreamHttp(_) => "upstream_http",
+            Self::UpstreamTimeout => "upstream_timeout",
+            Self::Internal => "internal",
+        }
+    }
+
+    /// What went wrong upstream, for the logs only.
+    pub fn detail(&self) -> Option<&str> {
+        match self {
+            Self::UpstreamShape(detail) | Self::UpstreamHttp(detail) => Some(detail),
+            _ => None,
+        }
+    }
+
+    /// Renders the error as HTML, JSON or plain text, whichever `Accept` prefers.
+    /// Server errors carry `request_id` in every format.
+    pub fn respond(
+        &self,
+        accept: Option<&HeaderValue>,
+        request_id: &str,
+    ) -> anyhow::Result<Response<Full<Bytes>>> {
+        let response = Response::builder().status(self.status());
+        let message = match self.status().is_server_error() {
+            true => format!("{self} (request ID {request_id})"),
+            false => self.to_string(),
+        };
+
+        if let Some(accept) = accept {
+            let accept_type = accept.priority(&["text/html", "application/json"])?;
+
+            if accept_type == "text/html" {
+                let body = match self {
+                    Self::RouteNotFound => load_template!("404.html", [("$title", APP_NAME)]),
+                    _ => load_template!(
+                        "error.html",
+                        [
+                            ("$title", APP_NAME),
+                            ("$heading", &self.heading()),
+                            ("$message", &escape_html(&message))
+                        ]
+                    ),
+                };
+
+                return Ok(response
+                    .header("Content-Type", "text/html")
+                    .body(respond_text!(body))?);
+            }
+
+            if accept_type == "application/json" {
+                let mut body = json!({
+                    "reason": self.to_string(),
+                    "code": self.code(),
+                    "success": false
+                });
+
+                if self.status().is_server_error() {
+                    body["requestId"] = json!(request_id);
+                }
+
+                return Ok(response
+                    .header("Content-Type", "application/json")
+                    .body(respond_text!(body.to_string()))?);
+            }
+        }
+
+        Ok(response.body(respond_text!(message))?)
+    }
+
+    fn heading(&self) -> String {
+        let status = self.status();
+
+        format!(
+            "{} {}",
+            status.as_u16(),
+            status.canonical_reason().unwrap_or_default()
+        )
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RouteNotFound => write!(f, "404 Not found"),
+            Self::BadRequest(reason) => write!(f, "{reason}"),
+            Self::NotFound(reason) => write!(f, "{reason}"),
+            Self::UpstreamShape(_) => write!(f, "Unexpected upstream response"),
+            Self::UpstreamHttp(_) => write!(f, "Upstream request failed"),
+            Self::UpstreamTimeout => write!(f, "Upstream took too long to respond"),
+            Self::Internal => write!(f, "Internal server error"),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            return Self::UpstreamTimeout;
+        }
+
+        Self::UpstreamHttp(err.to_string())
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,68 @@
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+use crate::{
+    error::{ApiError, ApiResult},
+    HTTP_CLIENT, USER_AGENT,
+};
+
+const GQL_ENDPOINT: &str = "https://gql.tokopedia.com/graphql";
+
+/// A persisted upstream GraphQL operation.
+pub struct Operation {
+    pub name: &'static str,
+    pub query: &'static str,
+    /// Value of the `X-Tkpd-Akamai` header, required by some operations
+    pub akamai: Option<&'static str>,
+}
+
+#[derive(Deserialize)]
+struct Envelope<T> {
+    data: T,
+}
+
+impl Operation {
+    /// Sends the operation and returns the raw upstream body.
+    pub async fn fetch(&self, variables: Value) -> ApiResult<String> {
+        let body = json!([
+            {
+                "operationName": self.name,
+                "variables": variables,
+                "query": self.query
+            }
+        ]);
+
+        let mut request = HTTP_CLIENT
+            .post(format!("{GQL_ENDPOINT}/{}", self.name))
+            .header("Accept", "*/*")
+            .header("Accept-Encoding", "gzip, deflate, br")
+            .header("Connection", "keep-alive")
+            .header("Content-Type", "application/json")
+            .header("Referer", "https://www.tokopedia.com/alifmodernwear/alf-by-alif-mukena-mini-two-tone-travel-gardenia?source=homepage.left_carousel.0.275921")
+            .header("User-Agent", USER_AGENT);
+
+        if let Some(akamai) = self.akamai {
+            request = request.header("X-Tkpd-Akamai", akamai);
+        }
+
+        Ok(request
+            .body(body.to_string())
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?)
+    }
+
+    /// Parses the `data` of the first batched result into `T`.
+    pub fn parse<T: DeserializeOwned>(&self, body: &str) -> ApiResult<T> {
+        let envelopes: Vec<Envelope<T>> = serde_json::from_str(body)
+            .map_err(|err| ApiError::shape(format!("{}: {err}", self.name)))?;
+
+        envelopes
+            .into_iter()
+            .next()
+            .map(|v| v.data)
+            .ok_or_else(|| ApiError::shape(format!("{}: empty batch", self.name)))
+    }
+}
@@ -0,0 +1,34 @@
+use crate::date::unix_timestamp;
+
+use super::{
+    model::Campaign,
+    upstream::{self, ThematicCampaign},
+};
+
+/// Builds the campaign section, `None` when the product is in no campaign.
+pub fn campaign(
+    campaign: Option<upstream::Campaign>,
+    thematic: Option<ThematicCampaign>,
+) -> Option<Campaign> {
+    let campaign = campaign.filter(|v| !v.campaign_id.is_empty() && v.campaign_id != "0")?;
+
+    let ends_at = match campaign.end_date_unix {
+        0 => unix_timestamp(&campaign.end_date),
+        end_date_unix => Some(end_date_unix),
+    };
+
+    Some(Campaign {
+        id: campaign.campaign_id,
+        type_name: campaign.campaign_type_name,
+        name: thematic.map(|v| v.campaign_name).unwrap_or_default(),
+        discount_percentage: campaign.percentage_amount,
+        original_price: campaign.original_price,
+        discounted_price: campaign.discounted_price,
+        original_stock: campaign.original_stock,
+        stock: campaign.stock,
+        sold_percentage: campaign.stock_sold_percentage,
+        starts_at: unix_timestamp(&campaign.start_date),
+        ends_at,
+        is_active: campaign.is_active,
+    })
+}
@@ -0,0 +1,32 @@
+use super::{
+    model::{MediaItem, Video},
+    upstream::ProductMedia,
+};
+
+/// Maps the `product_media` component onto the gallery and its videos.
+pub fn media_gallery(product_media: ProductMedia) -> (Vec<MediaItem>, Vec<Video>) {
+    let media = product_media
+        .media
+        .into_iter()
+        .map(|media| MediaItem {
+            kind: media.kind,
+            thumbnail: media.url_thumbnail,
+            original: media.url_original,
+            max_res: media.url_max_res,
+            video_url: Some(media.video_url).filter(|v| !v.is_empty()),
+            description: media.description,
+            variant_option_id: Some(media.variant_option_id).filter(|v| !v.is_empty() && v != "0"),
+        })
+        .collect();
+
+    let videos = product_media
+        .videos
+        .into_iter()
+        .map(|video| Video {
+            source: video.source,
+            url: video.url,
+        })
+        .collect();
+
+    (media, videos)
+}
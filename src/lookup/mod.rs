@@ -0,0 +1,201 @@
+mod campaign;
+mod media;
+mod model;
+mod upstream;
+mod variant;
+
+use serde_json::json;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    gql::Operation,
+    resolve,
+};
+
+use campaign::campaign;
+use media::media_gallery;
+pub use model::ProductLookup;
+use model::{
+    Category, CategoryCrumb, Preorder, ProductStats, Specification, Weight, WholesaleTier,
+};
+use upstream::{
+    PdpGetLayoutIdQuery, PdpGetLayoutQuery, ProductContent, ProductDetail, ProductMedia,
+    ProductVariant,
+};
+use variant::variant_matrix;
+
+/// Title of the `product_detail` row holding the description, per locale.
+const DESCRIPTION_TITLES: [&str; 2] = ["Deskripsi", "Description"];
+
+const OPERATION: Operation = Operation {
+    name: "PDPGetLayoutQuery",
+    query: include_str!("query.graphql"),
+    akamai: Some("pdpGetLayout"),
+};
+
+const ID_OPERATION: Operation = Operation {
+    name: "PDPGetLayoutQuery",
+    query: include_str!("product_id.graphql"),
+    akamai: Some("pdpGetLayout"),
+};
+
+/// Fetches the product layout through `operation`, 404 when there is no such
+/// product.
+async fn fetch_layout(operation: &Operation, seller: &str, product: &str) -> ApiResult<String> {
+    let response = operation
+        .fetch(json!({
+            "shopDomain": seller,
+            "productKey": product,
+            "layoutID": "",
+            "apiVersion": 1
+        }))
+        .await?;
+
+    if response.contains("product: not found") {
+        return Err(ApiError::NotFound("Product not found"));
+    }
+
+    Ok(response)
+}
+
+/// Finds the numeric product ID other upstream operations are keyed by.
+pub async fn product_id(seller: &str, product: &str) -> ApiResult<String> {
+    let response = fetch_layout(&ID_OPERATION, seller, product).await?;
+
+    Ok(ID_OPERATION
+        .parse::<PdpGetLayoutIdQuery>(&response)?
+        .pdp_get_layout
+        .basic_info
+        .id)
+}
+
+/// Looks up a product from its product page URL or share link.
+pub async fn lookup_url(url: &str) -> ApiResult<ProductLookup> {
+    let path = resolve::resolve(url).await?;
+
+    lookup(&path.shop_domain, &path.product_key).await
+}
+
+pub async fn lookup(seller: &str, product: &str) -> ApiResult<ProductLookup> {
+    let response = fetch_layout(&OPERATION, seller, product).await?;
+
+    let layout = OPERATION
+        .parse::<PdpGetLayoutQuery>(&response)?
+        .pdp_get_layout;
+
+    let mut content = None;
+    let mut description = "".to_string();
+    let mut specifications = Vec::new();
+    let mut variants = Vec::new();
+    let mut skus = Vec::new();
+    let mut media = Vec::new();
+    let mut videos = Vec::new();
+
+    for component in &layout.components {
+        if component.name == "product_content" {
+            content = Some(component.first::<ProductContent>()?);
+        }
+
+        if component.name == "product_media" {
+            (media, videos) = media_gallery(component.first::<ProductMedia>()?);
+        }
+
+        if component.kind == "variant" {
+            (variants, skus) = variant_matrix(component.first::<ProductVariant>()?);
+        }
+
+        if component.name == "product_detail" {
+            let detail = component.first::<ProductDetail>()?;
+
+            for content in detail.content {
+                if DESCRIPTION_TITLES.contains(&content.title.as_str()) {
+                    description = content.subtitle;
+                    continue;
+                }
+
+                specifications.push(Specification {
+                    title: content.title,
+                    value: content.subtitle,
+                    link: Some(content.applink).filter(|v| !v.is_empty()),
+                    show_at_front: content.show_at_front,
+                    is_annotation: content.is_annotation,
+                });
+            }
+        }
+    }
+
+    let content = content.ok_or_else(|| ApiError::shape("missing `product_content` component"))?;
+    let stock = content.stock.value;
+    let campaign = campaign(content.campaign, content.thematic_campaign);
+    let original_price = campaign
+        .as_ref()
+        .filter(|v| v.is_active && v.original_price > 0)
+        .map_or(content.price.value, |v| v.original_price);
+    let basic_info = layout.basic_info;
+    let category = basic_info.category;
+
+    Ok(ProductLookup {
+        success: true,
+        id: basic_info.id,
+        shop_id: basic_info.shop_id,
+        title: content.name,
+        description,
+        specifications,
+        price: content.price.value,
+        original_price,
+        stock: stock
+            .parse()
+            .map_err(|_| ApiError::shape(format!("unexpected stock value `{stock}`")))?,
+        store_name: basic_info.shop_name,
+        original_url: basic_info.url,
+        created_at: basic_info.created_at,
+        weight: Weight {
+            value: basic_info.weight,
+            unit: basic_info.weight_unit,
+        },
+        condition: basic_info.condition,
+        min_order: basic_info.min_order,
+        max_order: basic_info.max_order,
+        category: Category {
+            id: category.id,
+            name: category.name,
+            url: category.breadcrumb_url,
+            breadcrumb: category
+                .detail
+                .into_iter()
+                .map(|v| CategoryCrumb {
+                    id: v.id,
+                    name: v.name,
+                    url: v.breadcrumb_url,
+                })
+                .collect(),
+        },
+        sold: basic_info.tx_stats.count_sold,
+        stats: ProductStats {
+            view_count: basic_info.stats.count_view,
+            review_count: basic_info.stats.count_review,
+            talk_count: basic_info.stats.count_talk,
+            rating: basic_info.stats.rating,
+        },
+        cashback: content.is_cashback.percentage,
+        cod: content.is_cod,
+        preorder: content.preorder.is_active.then_some(Preorder {
+            duration: content.preorder.duration,
+            time_unit: content.preorder.time_unit,
+            days: content.preorder.preorder_in_days,
+        }),
+        wholesale: content
+            .wholesale
+            .into_iter()
+            .map(|v| WholesaleTier {
+                min_quantity: v.min_qty,
+                price: v.price.value,
+            })
+            .collect(),
+        campaign,
+        variants,
+        skus,
+        media,
+        videos,
+    })
+}
@@ -0,0 +1,198 @@
+use serde::Serialize;
+
+/// Body of `GET /lookup/{seller}/{product}`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductLookup {
+    pub success: bool,
+    pub id: String,
+    pub shop_id: String,
+    pub title: String,
+    pub description: String,
+    /// Rows of the product detail table other than the description
+    pub specifications: Vec<Specification>,
+    /// Effective price in rupiah, campaign discount included
+    pub price: u64,
+    /// Price in rupiah before any campaign discount
+    pub original_price: u64,
+    pub stock: usize,
+    pub store_name: String,
+    pub original_url: String,
+    pub created_at: String,
+    pub weight: Weight,
+    /// Upstream condition, e.g. `NEW` or `USED`
+    pub condition: String,
+    pub min_order: u32,
+    pub max_order: u32,
+    pub category: Category,
+    pub sold: u64,
+    pub stats: ProductStats,
+    /// Cashback percentage, `0` when there is none
+    pub cashback: u32,
+    /// Whether cash on delivery is available
+    pub cod: bool,
+    /// Present only for pre-order products
+    pub preorder: Option<Preorder>,
+    pub wholesale: Vec<WholesaleTier>,
+    /// Running or upcoming campaign (flash sale, promo), if any
+    pub campaign: Option<Campaign>,
+    /// Option axes such as colour and size, empty for single-SKU products
+    pub variants: Vec<VariantAxis>,
+    /// Every purchasable option combination
+    pub skus: Vec<Sku>,
+    /// Product gallery, in display order
+    pub media: Vec<MediaItem>,
+    pub videos: Vec<Video>,
+}
+
+#[derive(Serialize)]
+pub struct Weight {
+    pub value: u64,
+    /// Upstream unit, e.g. `GRAM`
+    pub unit: String,
+}
+
+#[derive(Serialize)]
+pub struct Category {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    /// From the top level category down to `name`
+    pub breadcrumb: Vec<CategoryCrumb>,
+}
+
+#[derive(Serialize)]
+pub struct CategoryCrumb {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductStats {
+    pub view_count: u64,
+    pub review_count: u64,
+    pub talk_count: u64,
+    pub rating: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Preorder {
+    pub duration: u32,
+    pub time_unit: String,
+    pub days: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WholesaleTier {
+    pub min_quantity: u32,
+    /// Unit price in rupiah from `min_quantity` onwards
+    pub price: u64,
+}
+
+#[derive(Serialize)]
+pub struct VariantAxis {
+    pub id: String,
+    pub name: String,
+    /// Upstream axis kind, e.g. `colour` or `size`
+    pub identifier: String,
+    pub options: Vec<VariantOptionValue>,
+}
+
+#[derive(Serialize)]
+pub struct VariantOptionValue {
+    pub id: String,
+    pub value: String,
+    /// Swatch colour for colour axes, empty otherwise
+    pub hex: String,
+    pub image: Option<Image>,
+}
+
+#[derive(Serialize)]
+pub struct Image {
+    pub original: String,
+    pub thumbnail: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Sku {
+    pub product_id: String,
+    pub name: String,
+    pub url: String,
+    /// One entry per axis, in `variants` order
+    pub options: Vec<SkuOption>,
+    /// Price in rupiah
+    pub price: u64,
+    pub stock: u64,
+    pub buyable: bool,
+    pub image: Option<Image>,
+}
+
+#[derive(Serialize)]
+pub struct SkuOption {
+    /// Name of the axis, e.g. `Warna`
+    pub variant: String,
+    pub value: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaItem {
+    /// `image` or `video`
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub thumbnail: String,
+    pub original: String,
+    pub max_res: String,
+    /// Video stream for `video` entries
+    pub video_url: Option<String>,
+    pub description: String,
+    /// Variant option this image illustrates, see `variants[].options[].id`
+    pub variant_option_id: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Video {
+    pub source: String,
+    pub url: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Campaign {
+    pub id: String,
+    /// Campaign type, e.g. `Flash Sale`
+    pub type_name: String,
+    /// Thematic campaign name, empty outside of thematic events
+    pub name: String,
+    pub discount_percentage: f64,
+    pub original_price: u64,
+    pub discounted_price: u64,
+    /// Units allocated to the campaign
+    pub original_stock: u64,
+    /// Units left in the campaign
+    pub stock: u64,
+    pub sold_percentage: f64,
+    /// Unix timestamp, `null` when the upstream did not send one
+    pub starts_at: Option<i64>,
+    /// Unix timestamp, `null` when the upstream did not send one
+    pub ends_at: Option<i64>,
+    pub is_active: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Specification {
+    /// Row label, e.g. `Kondisi` or `Etalase`
+    pub title: String,
+    pub value: String,
+    /// Upstream app link the value points to, if any
+    pub link: Option<String>,
+    /// Shown above the fold on the product page
+    pub show_at_front: bool,
+    pub is_annotation: bool,
+}
@@ -0,0 +1,325 @@
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+use crate::{
+    de,
+    error::{ApiError, ApiResult},
+};
+
+#[derive(Deserialize)]
+pub struct PdpGetLayoutQuery {
+    #[serde(rename = "pdpGetLayout")]
+    pub pdp_get_layout: PdpGetLayout,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdpGetLayout {
+    pub basic_info: BasicInfo,
+    pub components: Vec<Component>,
+}
+
+/// Trimmed down `pdpGetLayout`, only used to find a product's ID.
+#[derive(Deserialize)]
+pub struct PdpGetLayoutIdQuery {
+    #[serde(rename = "pdpGetLayout")]
+    pub pdp_get_layout: PdpGetLayoutId,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdpGetLayoutId {
+    pub basic_info: BasicInfoId,
+}
+
+#[derive(Deserialize)]
+pub struct BasicInfoId {
+    #[serde(deserialize_with = "de::string")]
+    pub id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasicInfo {
+    #[serde(deserialize_with = "de::string")]
+    pub id: String,
+    #[serde(rename = "shopID", deserialize_with = "de::string")]
+    pub shop_id: String,
+    pub shop_name: String,
+    pub url: String,
+    pub created_at: String,
+    #[serde(deserialize_with = "de::number")]
+    pub min_order: u32,
+    #[serde(deserialize_with = "de::number")]
+    pub max_order: u32,
+    #[serde(deserialize_with = "de::number")]
+    pub weight: u64,
+    pub weight_unit: String,
+    pub condition: String,
+    pub category: Category,
+    pub tx_stats: TxStats,
+    pub stats: Stats,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Category {
+    #[serde(deserialize_with = "de::string")]
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "breadcrumbURL")]
+    pub breadcrumb_url: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub detail: Vec<CategoryDetail>,
+}
+
+#[derive(Deserialize)]
+pub struct CategoryDetail {
+    #[serde(deserialize_with = "de::string")]
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "breadcrumbURL")]
+    pub breadcrumb_url: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxStats {
+    #[serde(deserialize_with = "de::number")]
+    pub count_sold: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Stats {
+    #[serde(deserialize_with = "de::number")]
+    pub count_view: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub count_review: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub count_talk: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub rating: f64,
+}
+
+/// A layout component. Its `data` shape depends on the component, so it is
+/// only parsed once we know which component we are looking at.
+#[derive(Deserialize)]
+pub struct Component {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub data: Vec<Value>,
+}
+
+impl Component {
+    pub fn first<T: DeserializeOwned>(&self) -> ApiResult<T> {
+        let data = self
+            .data
+            .first()
+            .ok_or_else(|| ApiError::shape(format!("`{}` component has no data", self.name)))?;
+
+        T::deserialize(data)
+            .map_err(|err| ApiError::shape(format!("`{}` component: {err}", self.name)))
+    }
+}
+
+/// `pdpDataProductContent`, the `product_content` component.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductContent {
+    pub name: String,
+    pub price: Price,
+    pub stock: Stock,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub wholesale: Vec<Wholesale>,
+    pub is_cashback: Cashback,
+    #[serde(rename = "isCOD")]
+    pub is_cod: bool,
+    pub preorder: Preorder,
+    pub campaign: Option<Campaign>,
+    pub thematic_campaign: Option<ThematicCampaign>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Campaign {
+    #[serde(rename = "campaignID", deserialize_with = "de::string")]
+    pub campaign_id: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub campaign_type_name: String,
+    #[serde(deserialize_with = "de::number")]
+    pub percentage_amount: f64,
+    #[serde(deserialize_with = "de::number")]
+    pub original_price: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub discounted_price: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub original_stock: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub stock: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub stock_sold_percentage: f64,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub start_date: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub end_date: String,
+    #[serde(deserialize_with = "de::number")]
+    pub end_date_unix: i64,
+    pub is_active: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThematicCampaign {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub campaign_name: String,
+}
+
+#[derive(Deserialize)]
+pub struct Price {
+    #[serde(deserialize_with = "de::number")]
+    pub value: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Wholesale {
+    #[serde(deserialize_with = "de::number")]
+    pub min_qty: u32,
+    pub price: Price,
+}
+
+#[derive(Deserialize)]
+pub struct Cashback {
+    #[serde(deserialize_with = "de::number")]
+    pub percentage: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Preorder {
+    #[serde(deserialize_with = "de::number")]
+    pub duration: u32,
+    pub time_unit: String,
+    pub is_active: bool,
+    #[serde(deserialize_with = "de::number")]
+    pub preorder_in_days: u32,
+}
+
+#[derive(Deserialize)]
+pub struct Stock {
+    pub value: String,
+}
+
+/// `pdpDataProductDetail`, the `product_detail` component.
+#[derive(Deserialize)]
+pub struct ProductDetail {
+    pub content: Vec<DetailContent>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetailContent {
+    pub title: String,
+    pub subtitle: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub applink: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub show_at_front: bool,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub is_annotation: bool,
+}
+
+/// `pdpDataProductVariant`, the `variant` component.
+#[derive(Deserialize)]
+pub struct ProductVariant {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub variants: Vec<Variant>,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub children: Vec<VariantChild>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Variant {
+    #[serde(rename = "productVariantID", deserialize_with = "de::string")]
+    pub product_variant_id: String,
+    pub name: String,
+    pub identifier: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub option: Vec<VariantOption>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantOption {
+    #[serde(rename = "productVariantOptionID", deserialize_with = "de::string")]
+    pub product_variant_option_id: String,
+    pub value: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub hex: String,
+    pub picture: Option<Picture>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Picture {
+    pub url_original: String,
+    pub url_thumbnail: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantChild {
+    #[serde(rename = "productID", deserialize_with = "de::string")]
+    pub product_id: String,
+    #[serde(deserialize_with = "de::number")]
+    pub price: u64,
+    #[serde(rename = "optionID", deserialize_with = "de::strings")]
+    pub option_id: Vec<String>,
+    pub product_name: String,
+    #[serde(rename = "productURL")]
+    pub product_url: String,
+    pub picture: Option<Picture>,
+    pub stock: VariantStock,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantStock {
+    #[serde(deserialize_with = "de::number")]
+    pub stock: u64,
+    pub is_buyable: bool,
+}
+
+/// `pdpDataProductMedia`, the `product_media` component.
+#[derive(Deserialize)]
+pub struct ProductMedia {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub media: Vec<Media>,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub videos: Vec<Video>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Media {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub url_original: String,
+    pub url_thumbnail: String,
+    pub url_max_res: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub video_url: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub description: String,
+    #[serde(rename = "variantOptionID", deserialize_with = "de::string")]
+    pub variant_option_id: String,
+}
+
+#[derive(Deserialize)]
+pub struct Video {
+    pub source: String,
+    pub url: String,
+}
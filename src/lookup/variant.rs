@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use super::{
+    model::{Image, Sku, SkuOption, VariantAxis, VariantOptionValue},
+    upstream::{Picture, ProductVariant},
+};
+
+impl From<Picture> for Image {
+    fn from(picture: Picture) -> Self {
+        Image {
+            original: picture.url_original,
+            thumbnail: picture.url_thumbnail,
+        }
+    }
+}
+
+/// Splits the `variant` component into its option axes and the SKUs, joining
+/// each child's `optionID`s back to the axis name and option value.
+pub fn variant_matrix(variant: ProductVariant) -> (Vec<VariantAxis>, Vec<Sku>) {
+    let mut option_names = HashMap::new();
+
+    for axis in &variant.variants {
+        for option in &axis.option {
+            option_names.insert(
+                option.product_variant_option_id.clone(),
+                (axis.name.clone(), option.value.clone()),
+            );
+        }
+    }
+
+    let skus = variant
+        .children
+        .into_iter()
+        .map(|child| Sku {
+            product_id: child.product_id,
+            name: child.product_name,
+            url: child.product_url,
+            options: child
+                .option_id
+                .iter()
+                .filter_map(|id| option_names.get(id))
+                .map(|(variant, value)| SkuOption {
+                    variant: variant.clone(),
+                    value: value.clone(),
+                })
+                .collect(),
+            price: child.price,
+            stock: child.stock.stock,
+            buyable: child.stock.is_buyable,
+            image: child.picture.map(Image::from),
+        })
+        .collect();
+
+    let axes = variant
+        .variants
+        .into_iter()
+        .map(|axis| VariantAxis {
+            id: axis.product_variant_id,
+            name: axis.name,
+            identifier: axis.identifier,
+            options: axis
+                .option
+                .into_iter()
+                .map(|option| VariantOptionValue {
+                    id: option.product_variant_option_id,
+                    value: option.value,
+                    hex: option.hex,
+                    image: option.picture.map(Image::from),
+                })
+                .collect(),
+        })
+        .collect();
+
+    (axes, skus)
+}
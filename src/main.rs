@@ -1,4 +1,11 @@
-use anyhow::{bail, Ok, Result};
+use std::{
+    borrow::Cow,
+    convert::Infallible,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Ok, Result};
 use http_body_util::Full;
 use hyper::{
     body::{Bytes, Incoming},
@@ -8,7 +15,9 @@ use hyper::{
     Method, Request, Response,
 };
 use once_cell::sync::Lazy;
-use serde_json::{json, Value};
+use percent_encoding::percent_decode_str;
+use serde::Serialize;
+use serde_json::json;
 use tokio::net::TcpListener;
 
 macro_rules! respond_text {
@@ -19,8 +28,15 @@ macro_rules! respond_text {
 
 const APP_NAME: &str = "Tokopedia Client API";
 
-static HTTP_CLIENT: once_cell::sync::Lazy<reqwest::Client> =
-    Lazy::new(|| reqwest::Client::builder().build().unwrap());
+/// Desktop browser user agent sent with every upstream request.
+const USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36";
+
+static HTTP_CLIENT: once_cell::sync::Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(20))
+        .build()
+        .unwrap()
+});
 
 macro_rules! build_id {
     () => {
@@ -43,49 +59,25 @@ macro_rules! load_template {
     }};
 }
 
-trait QuickParser {
-    fn get_value_between<'a>(&'a self, val1: &str, val2: &str) -> Result<&'a str>;
-}
-
-impl QuickParser for String {
-    fn get_value_between<'a>(&'a self, val1: &str, val2: &str) -> Result<&'a str> {
-        let result = self
-            .split_once(val1)
-            .unwrap_or(("", ""))
-            .1
-            .split_once(val2)
-            .unwrap_or(("", ""))
-            .0;
-
-        if result.len() <= 0 {
-            bail!("");
-        }
-
-        Ok(result)
-    }
-}
-
-impl QuickParser for &'static str {
-    fn get_value_between<'a>(&'a self, val1: &str, val2: &str) -> Result<&'a str> {
-        let result = self
-            .split_once(val1)
-            .unwrap_or(("", ""))
-            .1
-            .split_once(val2)
-            .unwrap_or(("", ""))
-            .0;
-
-        if result.len() <= 0 {
-            bail!("");
-        }
-
-        Ok(result)
-    }
-}
+mod category;
+mod date;
+mod de;
+mod discussion;
+mod error;
+mod gql;
+mod lookup;
+mod query;
+mod resolve;
+mod review;
+mod search;
+mod shop;
+mod suggest;
+
+use error::{ApiError, ApiResult};
+use query::Query;
 
 trait Accept {
     fn to_vec(&self) -> Result<Vec<String>>;
-    fn has(&self, value: &str) -> Result<bool>;
     fn priority(&self, value: &[&str]) -> Result<String>;
 }
 
@@ -96,26 +88,22 @@ impl Accept for HeaderValue {
             .split(",")
             .map(|v| v.trim().to_string())
             .map(|v| {
-                v.split(";")
+                v.split(';')
                     .collect::<Vec<&str>>()
-                    .get(0)
+                    .first()
                     .unwrap()
                     .to_string()
             })
             .map(|v| {
-                v.split("+")
+                v.split('+')
                     .collect::<Vec<&str>>()
-                    .get(0)
+                    .first()
                     .unwrap()
                     .to_string()
             })
             .collect())
     }
 
-    fn has(&self, value: &str) -> Result<bool> {
-        Ok(self.to_vec()?.contains(&value.to_string()))
-    }
-
     fn priority(&self, value: &[&str]) -> Result<String> {
         let value = self
             .to_vec()?
@@ -123,7 +111,7 @@ impl Accept for HeaderValue {
             .filter(|v| value.to_vec().contains(&v.as_str()))
             .cloned()
             .collect::<Vec<String>>();
-        Ok(value.get(0).unwrap_or(&"".to_string()).to_string())
+        Ok(value.first().unwrap_or(&"".to_string()).to_string())
     }
 }
 
@@ -133,263 +121,191 @@ async fn service(req: Request<Incoming>) -> Result<Response<Full<Bytes>>> {
     }
 
     let accept = req.headers().get("Accept");
-
-    match (req.method(), req.uri().path()) {
-        (&Method::GET, "/") => {
-            if let Some(accept) = accept {
-                let accept_type = accept.priority(&["text/html", "application/json"])?;
-
-                if accept_type == "text/html" {
-                    return Ok(Response::builder()
-                        .header("Content-Type", "text/html")
-                        .body(respond_text!(load_template!(
-                            "version.html",
-                            [("$title", APP_NAME), ("$build", &build_id!())]
-                        )))?);
-                }
-
-                if accept_type == "application/json" {
-                    return Ok(Response::builder()
-                        .header("Content-Type", "application/json")
-                        .body(respond_text!(json!({
-                            "name": APP_NAME,
-                            "build": build_id!(),
-                            "success": true
-                        })
-                        .to_string()))?);
-                }
+    let query = Query::parse(req.uri().query());
+
+    if req.method() == Method::GET && req.uri().path() == "/" {
+        if let Some(accept) = accept {
+            let accept_type = accept.priority(&["text/html", "application/json"])?;
+
+            if accept_type == "text/html" {
+                return Ok(Response::builder()
+                    .header("Content-Type", "text/html")
+                    .body(respond_text!(load_template!(
+                        "version.html",
+                        [("$title", APP_NAME), ("$build", &build_id!())]
+                    )))?);
             }
 
-            return Ok(Response::new(respond_text!(app_desc!())));
+            if accept_type == "application/json" {
+                return Ok(Response::builder()
+                    .header("Content-Type", "application/json")
+                    .body(respond_text!(json!({
+                        "name": APP_NAME,
+                        "build": build_id!(),
+                        "success": true
+                    })
+                    .to_string()))?);
+            }
         }
-        _ => {}
+
+        return Ok(Response::new(respond_text!(app_desc!())));
     }
 
     let splitted_path = req
         .uri()
         .path()
-        .split("/")
-        .filter(|v| v.len() > 0)
-        .collect::<Vec<&str>>();
-
-    if splitted_path.len() >= 1 {
-        let request_type = splitted_path[0];
-
-        if splitted_path.len() >= 2 {
-            match (req.method(), splitted_path.len(), request_type) {
-                (&Method::GET, 2, "search") => {
-                    let search_query = splitted_path[1];
-
-                    let body = serde_json::json!([
-                      {
-                        "operationName": "SearchProductQueryV4",
-                        "variables": {
-                          "params": format!("device=desktop&navsource=home&ob=23&page=1&q={search_query}&related=true&rows=20&safe_search=false&scheme=https&shipping=&source=universe&st=product&start=0&topads_bucket=true")
-                        },
-                        "query": "query SearchProductQueryV4($params: String!) {\n  ace_search_product_v4(params: $params) {\n    header {\n      totalData\n      totalDataText\n      processTime\n      responseCode\n      errorMessage\n      additionalParams\n      keywordProcess\n      componentId\n      __typename\n    }\n    data {\n      banner {\n        position\n        text\n        imageUrl\n        url\n        componentId\n        trackingOption\n        __typename\n      }\n      backendFilters\n      isQuerySafe\n      ticker {\n        text\n        query\n        typeId\n        componentId\n        trackingOption\n        __typename\n      }\n      redirection {\n        redirectUrl\n        departmentId\n        __typename\n      }\n      related {\n        position\n        trackingOption\n        relatedKeyword\n        otherRelated {\n          keyword\n          url\n          product {\n            id\n            name\n            price\n            imageUrl\n            rating\n            countReview\n            url\n            priceStr\n            wishlist\n            shop {\n              city\n              isOfficial\n              isPowerBadge\n              __typename\n            }\n            ads {\n              adsId: id\n              productClickUrl\n              productWishlistUrl\n              shopClickUrl\n              productViewUrl\n              __typename\n            }\n            badges {\n              title\n              imageUrl\n              show\n              __typename\n            }\n            ratingAverage\n            labelGroups {\n              position\n              type\n              title\n              url\n              __typename\n            }\n            componentId\n            __typename\n          }\n          componentId\n          __typename\n        }\n        __typename\n      }\n      suggestion {\n        currentKeyword\n        suggestion\n        suggestionCount\n        instead\n        insteadCount\n        query\n        text\n        componentId\n        trackingOption\n        __typename\n      }\n      products {\n        id\n        name\n        ads {\n          adsId: id\n          productClickUrl\n          productWishlistUrl\n          productViewUrl\n          __typename\n        }\n        badges {\n          title\n          imageUrl\n          show\n          __typename\n        }\n        category: departmentId\n        categoryBreadcrumb\n        categoryId\n        categoryName\n        countReview\n        customVideoURL\n        discountPercentage\n        gaKey\n        imageUrl\n        labelGroups {\n          position\n          title\n          type\n          url\n          __typename\n        }\n        originalPrice\n        price\n        priceRange\n        rating\n        ratingAverage\n        shop {\n          shopId: id\n          name\n          url\n          city\n          isOfficial\n          isPowerBadge\n          __typename\n        }\n        url\n        wishlist\n        sourceEngine: source_engine\n        __typename\n      }\n      violation {\n        headerText\n        descriptionText\n        imageURL\n        ctaURL\n        ctaApplink\n        buttonText\n        buttonType\n        __typename\n      }\n      __typename\n    }\n    __typename\n  }\n}\n"
-                      }
-                    ]);
-
-                    let response = HTTP_CLIENT
-                        .post("https://gql.tokopedia.com/graphql/PDPGetLayoutQuery")
-                        .header("Accept", "*/*")
-                        .header("Accept-Encoding", "gzip, deflate, br")
-                        .header("Connection", "keep-alive")
-                        .header("Content-Type", "application/json")
-                        .header("Referer", "https://www.tokopedia.com/alifmodernwear/alf-by-alif-mukena-mini-two-tone-travel-gardenia?source=homepage.left_carousel.0.275921")
-                        .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36")
-                        .body(body.to_string())
-                        .send()
-                        .await?
-                        .text()
-                        .await?;
-
-                    let response: Value = serde_json::from_str(&response)?;
-
-                    let data = response[0]["data"]["ace_search_product_v4"]["data"].clone();
-
-                    let current_keyword = data["suggestion"]["currentKeyword"].as_str().unwrap();
-                    let suggestion = data["suggestion"]["suggestion"].as_str().unwrap();
-
-                    let products = data["products"].as_array().unwrap();
-
-                    let mut result_products = Vec::new();
-
-                    for product in products {
-                        let shop_name = product["shop"]["name"].as_str().unwrap();
-                        let shop_url = product["shop"]["url"].as_str().unwrap();
-                        let shop_city = product["shop"]["city"].as_str().unwrap();
-                        let shop_is_official = product["shop"]["isOfficial"].as_bool().unwrap();
-                        let shop_is_powerbadge = product["shop"]["isPowerBadge"].as_bool().unwrap();
-                        let shop_username = shop_url.replace("https://www.tokopedia.com/", "");
-
-                        let product_name = product["name"].as_str().unwrap();
-                        let product_url = product["url"].as_str().unwrap();
-                        let product_price = product["price"].as_str().unwrap();
-                        let product_thumbnail = product["imageUrl"].as_str().unwrap();
-                        let product_category = product["categoryName"].as_str().unwrap();
-                        let product_id = product_url
-                            .to_string()
-                            .get_value_between(&format!("{shop_username}/"), "?")?
-                            .to_string();
-
-                        result_products.push(json!({
-                            "seller": {
-                                "name": shop_name,
-                                "id": shop_username,
-                                "url": shop_url,
-                                "city": shop_city,
-                                "isOfficial": shop_is_official,
-                                "hasPowerBadge": shop_is_powerbadge
-                            },
-                            "name": product_name,
-                            "url": product_url,
-                            "price": product_price,
-                            "thumbnail": product_thumbnail,
-                            "category": product_category,
-                            "id": product_id
-                        }));
-                    }
-
-                    return Ok(Response::builder()
-                        .header("Content-Type", "application/json")
-                        .body(respond_text!(json!({
-                            "success": true,
-                            "keyword": current_keyword,
-                            "suggestion": suggestion,
-                            "results": result_products
-                        })
-                        .to_string()))?);
-                }
-                (&Method::GET, 3, "lookup") => {
-                    let seller = splitted_path[1];
-                    let product = splitted_path[2];
-
-                    let body = serde_json::json!([
-                      {
-                        "operationName": "PDPGetLayoutQuery",
-                        "variables": {
-                          "shopDomain": seller,
-                          "productKey": product,
-                          "layoutID": "",
-                          "apiVersion": 1
-                        },
-                        "query": "fragment ProductVariant on pdpDataProductVariant {\n  errorCode\n  parentID\n  defaultChild\n  sizeChart\n  totalStockFmt\n  variants {\n    productVariantID\n    variantID\n    name\n    identifier\n    option {\n      picture {\n        urlOriginal: url\n        urlThumbnail: url100\n        __typename\n      }\n      productVariantOptionID\n      variantUnitValueID\n      value\n      hex\n      stock\n      __typename\n    }\n    __typename\n  }\n  children {\n    productID\n    price\n    priceFmt\n    optionID\n    optionName\n    productName\n    productURL\n    picture {\n      urlOriginal: url\n      urlThumbnail: url100\n      __typename\n    }\n    stock {\n      stock\n      isBuyable\n      stockWordingHTML\n      minimumOrder\n      maximumOrder\n      __typename\n    }\n    isCOD\n    isWishlist\n    campaignInfo {\n      campaignID\n      campaignType\n      campaignTypeName\n      campaignIdentifier\n      background\n      discountPercentage\n      originalPrice\n      discountPrice\n      stock\n      stockSoldPercentage\n      startDate\n      endDate\n      endDateUnix\n      appLinks\n      isAppsOnly\n      isActive\n      hideGimmick\n      isCheckImei\n      minOrder\n      __typename\n    }\n    thematicCampaign {\n      additionalInfo\n      background\n      campaignName\n      icon\n      __typename\n    }\n    __typename\n  }\n  __typename\n}\n\nfragment ProductMedia on pdpDataProductMedia {\n  media {\n    type\n    urlOriginal: URLOriginal\n    urlThumbnail: URLThumbnail\n    urlMaxRes: URLMaxRes\n    videoUrl: videoURLAndroid\n    prefix\n    suffix\n    description\n    variantOptionID\n    __typename\n  }\n  videos {\n    source\n    url\n    __typename\n  }\n  __typename\n}\n\nfragment ProductCategoryCarousel on pdpDataCategoryCarousel {\n  linkText\n  titleCarousel\n  applink\n  list {\n    categoryID\n    icon\n    title\n    isApplink\n    applink\n    __typename\n  }\n  __typename\n}\n\nfragment ProductHighlight on pdpDataProductContent {\n  name\n  price {\n    value\n    currency\n    __typename\n  }\n  campaign {\n    campaignID\n    campaignType\n    campaignTypeName\n    campaignIdentifier\n    background\n    percentageAmount\n    originalPrice\n    discountedPrice\n    originalStock\n    stock\n    stockSoldPercentage\n    threshold\n    startDate\n    endDate\n    endDateUnix\n    appLinks\n    isAppsOnly\n    isActive\n    hideGimmick\n    __typename\n  }\n  thematicCampaign {\n    additionalInfo\n    background\n    campaignName\n    icon\n    __typename\n  }\n  stock {\n    useStock\n    value\n    stockWording\n    __typename\n  }\n  variant {\n    isVariant\n    parentID\n    __typename\n  }\n  wholesale {\n    minQty\n    price {\n      value\n      currency\n      __typename\n    }\n    __typename\n  }\n  isCashback {\n    percentage\n    __typename\n  }\n  isTradeIn\n  isOS\n  isPowerMerchant\n  isWishlist\n  isCOD\n  preorder {\n    duration\n    timeUnit\n    isActive\n    preorderInDays\n    __typename\n  }\n  __typename\n}\n\nfragment ProductCustomInfo on pdpDataCustomInfo {\n  icon\n  title\n  isApplink\n  applink\n  separator\n  description\n  __typename\n}\n\nfragment ProductInfo on pdpDataProductInfo {\n  row\n  content {\n    title\n    subtitle\n    applink\n    __typename\n  }\n  __typename\n}\n\nfragment ProductDetail on pdpDataProductDetail {\n  content {\n    title\n    subtitle\n    applink\n    showAtFront\n    isAnnotation\n    __typename\n  }\n  __typename\n}\n\nfragment ProductDataInfo on pdpDataInfo {\n  icon\n  title\n  isApplink\n  applink\n  content {\n    icon\n    text\n    __typename\n  }\n  __typename\n}\n\nfragment ProductSocial on pdpDataSocialProof {\n  row\n  content {\n    icon\n    title\n    subtitle\n    applink\n    type\n    rating\n    __typename\n  }\n  __typename\n}\n\nquery PDPGetLayoutQuery($shopDomain: String, $productKey: String, $layoutID: String, $apiVersion: Float, $userLocation: pdpUserLocation, $extParam: String, $tokonow: pdpTokoNow) {\n  pdpGetLayout(shopDomain: $shopDomain, productKey: $productKey, layoutID: $layoutID, apiVersion: $apiVersion, userLocation: $userLocation, extParam: $extParam, tokonow: $tokonow) {\n    requestID\n    name\n    pdpSession\n    basicInfo {\n      alias\n      createdAt\n      isQA\n      id: productID\n      shopID\n      shopName\n      minOrder\n      maxOrder\n      weight\n      weightUnit\n      condition\n      status\n      url\n      needPrescription\n      catalogID\n      isLeasing\n      isBlacklisted\n      isTokoNow\n      menu {\n        id\n        name\n        url\n        __typename\n      }\n      category {\n        id\n        name\n        title\n        breadcrumbURL\n        isAdult\n        isKyc\n        minAge\n        detail {\n          id\n          name\n          breadcrumbURL\n          isAdult\n          __typename\n        }\n        __typename\n      }\n      txStats {\n        transactionSuccess\n        transactionReject\n        countSold\n        paymentVerified\n        itemSoldFmt\n        __typename\n      }\n      stats {\n        countView\n        countReview\n        countTalk\n        rating\n        __typename\n      }\n      __typename\n    }\n    components {\n      name\n      type\n      position\n      data {\n        ...ProductMedia\n        ...ProductHighlight\n        ...ProductInfo\n        ...ProductDetail\n        ...ProductSocial\n        ...ProductDataInfo\n        ...ProductCustomInfo\n        ...ProductVariant\n        ...ProductCategoryCarousel\n        __typename\n      }\n      __typename\n    }\n    __typename\n  }\n}\n"
-                      }
-                    ]);
-
-                    let response = HTTP_CLIENT
-                        .post("https://gql.tokopedia.com/graphql/PDPGetLayoutQuery")
-                        .header("X-Tkpd-Akamai", "pdpGetLayout")
-                        .header("Accept", "*/*")
-                        .header("Accept-Encoding", "gzip, deflate, br")
-                        .header("Connection", "keep-alive")
-                        .header("Content-Type", "application/json")
-                        .header("Referer", "https://www.tokopedia.com/alifmodernwear/alf-by-alif-mukena-mini-two-tone-travel-gardenia?source=homepage.left_carousel.0.275921")
-                        .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36")
-                        .body(body.to_string())
-                        .send()
-                        .await?
-                        .text()
-                        .await?;
-
-                    if response.contains("product: not found") {
-                        return Ok(Response::builder()
-                            .header("Content-Type", "application/json")
-                            .body(respond_text!(json!({
-                                "reason": "Product not found",
-                                "success": false
-                            })
-                            .to_string()))?);
-                    }
-
-                    let response: Value = serde_json::from_str(&response)?;
-
-                    let components = response[0]["data"]["pdpGetLayout"]["components"]
-                        .as_array()
-                        .unwrap();
-                    let basic_info = &response[0]["data"]["pdpGetLayout"]["basicInfo"];
-
-                    let mut title = "".to_string();
-                    let mut description = "".to_string();
-                    let mut price = 0;
-                    let mut stock = "0".to_string();
-
-                    let store_name = basic_info["shopName"].as_str().unwrap();
-                    let original_url = basic_info["url"].as_str().unwrap();
-                    let created_at = basic_info["createdAt"].as_str().unwrap();
-
-                    for component in components {
-                        let component_name = component["name"].as_str().unwrap();
-
-                        if component_name == "product_content" {
-                            let data = component["data"][0].clone();
-
-                            title = data["name"].as_str().unwrap().to_string();
-                            price = data["price"]["value"].as_u64().unwrap();
-                            stock = data["stock"]["value"].as_str().unwrap().to_string();
-                        }
-
-                        if component_name == "product_detail" {
-                            let contents = component["data"][0]["content"].as_array().unwrap();
-
-                            for content in contents {
-                                let title = content["title"].as_str().unwrap();
-
-                                if title == "Deskripsi" {
-                                    description = content["subtitle"].as_str().unwrap().to_string();
-                                }
-                            }
-                        }
-                    }
-
-                    return Ok(Response::builder()
-                        .header("Content-Type", "application/json")
-                        .body(respond_text!(json!({
-                            "success": true,
-                            "title": title,
-                            "description": description,
-                            "price": price,
-                            "stock": stock.parse::<usize>()?,
-                            "storeName": store_name,
-                            "originalUrl": original_url,
-                            "createdAt": created_at
-                        })
-                        .to_string()))?);
-                }
-                _ => {}
+        .split('/')
+        .filter(|v| !v.is_empty())
+        .map(|v| percent_decode_str(v).decode_utf8_lossy())
+        .collect::<Vec<Cow<str>>>();
+
+    if let [request_type, ..] = &splitted_path[..] {
+        match (req.method(), splitted_path.len(), request_type.as_ref()) {
+            (&Method::GET, 1, "search") => {
+                let result = match query.get("q") {
+                    Some(search_query) => search::search(search_query, &query).await,
+                    None => Err(ApiError::BadRequest("`q` is required".to_string())),
+                };
+
+                return respond(result);
+            }
+            (&Method::GET, 2, "search") => {
+                return respond(search::search(&splitted_path[1], &query).await);
+            }
+            (&Method::GET, 1, "categories") => {
+                return respond(category::tree().await);
+            }
+            (&Method::GET, 3, "categories") if splitted_path[2] == "products" => {
+                return respond(search::category(&splitted_path[1], &query).await);
+            }
+            (&Method::GET, 2, "suggest") => {
+                return respond(suggest::suggest(&splitted_path[1]).await);
+            }
+            (&Method::GET, 1, "lookup") => {
+                let result = match query.get("url") {
+                    Some(url) => lookup::lookup_url(url).await,
+                    None => Err(ApiError::BadRequest("`url` is required".to_string())),
+                };
+
+                return respond(result);
+            }
+            (&Method::GET, 1, "resolve") => {
+                let result = match query.get("url") {
+                    Some(url) => resolve::resolve_url(url).await,
+                    None => Err(ApiError::BadRequest("`url` is required".to_string())),
+                };
+
+                return respond(result);
             }
+            (&Method::GET, 2, "shop") => {
+                return respond(shop::profile(&splitted_path[1]).await);
+            }
+            (&Method::GET, 3, "shop") if splitted_path[2] == "products" => {
+                return respond(shop::products(&splitted_path[1], &query).await);
+            }
+            (&Method::GET, 3, "shop") if splitted_path[2] == "etalase" => {
+                return respond(shop::etalase(&splitted_path[1]).await);
+            }
+            (&Method::GET, 3, "lookup") => {
+                return respond(lookup::lookup(&splitted_path[1], &splitted_path[2]).await);
+            }
+            (&Method::GET, 4, "lookup") if splitted_path[3] == "reviews" => {
+                return respond(
+                    review::reviews(&splitted_path[1], &splitted_path[2], &query).await,
+                );
+            }
+            (&Method::GET, 4, "lookup") if splitted_path[3] == "discussions" => {
+                return respond(
+                    discussion::discussions(&splitted_path[1], &splitted_path[2], &query).await,
+                );
+            }
+            (&Method::GET, 5, "lookup")
+                if splitted_path[3] == "reviews" && splitted_path[4] == "summary" =>
+            {
+                return respond(review::summary(&splitted_path[1], &splitted_path[2]).await);
+            }
+            _ => {}
         }
     }
 
-    if let Some(accept) = accept {
-        let accept_type = accept.priority(&["text/html", "application/json"])?;
-
-        if accept_type == "text/html" {
-            return Ok(Response::builder()
-                .status(404)
-                .header("Content-Type", "text/html")
-                .body(respond_text!(load_template!(
-                    "404.html",
-                    [("$title", APP_NAME)]
-                )))?);
+    Err(ApiError::RouteNotFound.into())
+}
+
+/// Serializes a route result, leaving its error for `handle` to render.
+fn respond<T: Serialize>(result: ApiResult<T>) -> Result<Response<Full<Bytes>>> {
+    match result {
+        Result::Ok(value) => Ok(Response::builder()
+            .header("Content-Type", "application/json")
+            .body(respond_text!(serde_json::to_string(&value)?))?),
+        Err(err) => Err(err.into()),
+    }
+}
+
+static REQUEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn next_request_id() -> String {
+    let started = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|v| v.as_secs())
+        .unwrap_or_default();
+
+    format!(
+        "{started:x}-{:06x}",
+        REQUEST_COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Runs `service` on its own task and turns any error or panic it produces
+/// into a response, so one failing request never closes the connection.
+/// Every response carries an `X-Request-Id` header.
+async fn handle(req: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    let request_id = next_request_id();
+    let accept = req.headers().get("Accept").cloned();
+
+    let mut response = match tokio::spawn(service(req)).await {
+        Result::Ok(Result::Ok(response)) => response,
+        Result::Ok(Err(err)) => {
+            let error = err.downcast::<ApiError>().unwrap_or_else(|err| {
+                eprintln!("[{request_id}] {err:#}");
+
+                ApiError::Internal
+            });
+
+            error_response(&error, accept.as_ref(), &request_id)
         }
+        Err(err) => {
+            eprintln!("[{request_id}] handler panicked: {err}");
+
+            error_response(&ApiError::Internal, accept.as_ref(), &request_id)
+        }
+    };
+
+    if let Result::Ok(request_id) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert("X-Request-Id", request_id);
+    }
+
+    Result::Ok(response)
+}
 
-        if accept_type == "application/json" {
-            return Ok(Response::builder()
-                .status(404)
-                .header("Content-Type", "application/json")
-                .body(respond_text!(json!({
-                    "reason": "404 Not found",
-                    "success": false
-                })
-                .to_string()))?);
+/// Renders `error`, logging it first when it is a server error.
+fn error_response(
+    error: &ApiError,
+    accept: Option<&HeaderValue>,
+    request_id: &str,
+) -> Response<Full<Bytes>> {
+    // Internal errors have already been logged along with their cause
+    if error.status().is_server_error() && !matches!(error, ApiError::Internal) {
+        match error.detail() {
+            Some(detail) => eprintln!("[{request_id}] {error}: {detail}"),
+            None => eprintln!("[{request_id}] {error}"),
         }
     }
 
-    Ok(Response::builder()
-        .status(404)
-        .body(respond_text!("404 Not found"))?)
+    error.respond(accept, request_id).unwrap_or_else(|_| {
+        let mut response = Response::new(respond_text!(error.to_string()));
+        *response.status_mut() = error.status();
+        response
+    })
 }
 
 #[tokio::main]
@@ -400,7 +316,7 @@ async fn main() -> Result<()> {
 
     println!(
         "Server started at {ip_addr}:{port}",
-        ip_addr = listener.local_addr()?.ip().to_string(),
+        ip_addr = listener.local_addr()?.ip(),
         port = listener.local_addr()?.port()
     );
 
@@ -409,7 +325,7 @@ async fn main() -> Result<()> {
 
         tokio::task::spawn(async move {
             if let Err(err) = http1::Builder::new()
-                .serve_connection(stream, service_fn(service))
+                .serve_connection(stream, service_fn(handle))
                 .await
             {
                 eprintln!("Something is wrong: {:?}", err)
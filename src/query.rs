@@ -0,0 +1,124 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::error::{ApiError, ApiResult};
+
+/// Decoded query string of an incoming request.
+pub struct Query(HashMap<String, String>);
+
+impl Query {
+    pub fn parse(query: Option<&str>) -> Self {
+        Self(
+            url::form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+                .into_owned()
+                .collect(),
+        )
+    }
+
+    /// Returns a parameter, treating an empty value as absent.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(|v| v.trim()).filter(|v| !v.is_empty())
+    }
+
+    /// Parses a numeric parameter, answering 400 when it is not a number.
+    pub fn number<T: FromStr>(&self, key: &str, default: T) -> ApiResult<T> {
+        Ok(self.optional_number(key)?.unwrap_or(default))
+    }
+
+    pub fn optional_number<T: FromStr>(&self, key: &str) -> ApiResult<Option<T>> {
+        self.get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| ApiError::BadRequest(format!("`{key}` must be a number")))
+            })
+            .transpose()
+    }
+
+    /// Picks the entry of `choices` named by a parameter, the first one when
+    /// it is absent, answering 400 with the accepted names otherwise.
+    pub fn choice<T: Copy>(
+        &self,
+        key: &str,
+        choices: &[T],
+        name: fn(&T) -> &'static str,
+    ) -> ApiResult<T> {
+        let Some(value) = self.get(key) else {
+            return Ok(choices[0]);
+        };
+
+        choices
+            .iter()
+            .find(|v| name(v) == value)
+            .copied()
+            .ok_or_else(|| {
+                let names = choices.iter().map(name).collect::<Vec<_>>().join(", ");
+                ApiError::BadRequest(format!("`{key}` must be one of: {names}"))
+            })
+    }
+
+    /// Parses `page`, starting at 1, and the page size parameter `key`, from 1
+    /// to `max`.
+    pub fn page_and_size(&self, key: &str, default: u32, max: u32) -> ApiResult<(u32, u32)> {
+        let page = self.number("page", 1)?;
+        let size = self.number(key, default)?;
+
+        if page == 0 {
+            return Err(ApiError::BadRequest("`page` starts at 1".to_string()));
+        }
+
+        if size == 0 || size > max {
+            return Err(ApiError::BadRequest(format!(
+                "`{key}` must be between 1 and {max}"
+            )));
+        }
+
+        Ok((page, size))
+    }
+
+    /// Parses a `true`/`false` (or `1`/`0`) parameter, absent means `false`.
+    pub fn flag(&self, key: &str) -> ApiResult<bool> {
+        match self.get(key) {
+            None | Some("false") | Some("0") => Ok(false),
+            Some("true") | Some("1") => Ok(true),
+            Some(_) => Err(ApiError::BadRequest(format!(
+                "`{key}` must be true or false"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(value: &u8) -> &'static str {
+        ["one", "two", "three"][*value as usize]
+    }
+
+    #[test]
+    fn choice_defaults_to_the_first_entry() {
+        let query = Query::parse(Some("sort=three&empty="));
+
+        assert_eq!(query.choice("sort", &[0, 1, 2], name).ok(), Some(2));
+        assert_eq!(query.choice("empty", &[1, 2], name).ok(), Some(1));
+
+        match query.choice("sort", &[0, 1], name) {
+            Err(ApiError::BadRequest(reason)) => {
+                assert_eq!(reason, "`sort` must be one of: one, two")
+            }
+            _ => panic!("expected a bad request"),
+        }
+    }
+
+    #[test]
+    fn page_and_size_checks_bounds() {
+        let page_and_size = |query| Query::parse(Some(query)).page_and_size("limit", 10, 50);
+
+        assert_eq!(page_and_size("").ok(), Some((1, 10)));
+        assert_eq!(page_and_size("page=3&limit=50").ok(), Some((3, 50)));
+
+        for query in ["page=0", "limit=0", "limit=51", "page=x"] {
+            assert!(page_and_size(query).is_err(), "{query}");
+        }
+    }
+}
@@ -0,0 +1,353 @@
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use reqwest::redirect::Policy;
+use serde::Serialize;
+use url::Url;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    USER_AGENT,
+};
+
+/// Hosts serving product pages as `/{shop}/{product}`.
+const PRODUCT_HOSTS: [&str; 3] = ["www.tokopedia.com", "tokopedia.com", "m.tokopedia.com"];
+/// Hosts of the app share links, which redirect to a product page.
+const SHORT_LINK_HOSTS: [&str; 2] = ["tokopedia.link", "tk.tokopedia.link"];
+const CANONICAL_ORIGIN: &str = "https://www.tokopedia.com/";
+const MAX_REDIRECTS: usize = 10;
+
+/// First path segments of site pages that are not shops.
+const RESERVED_PATHS: [&str; 20] = [
+    "about",
+    "blog",
+    "cart",
+    "careers",
+    "discovery",
+    "edu",
+    "events",
+    "find",
+    "help",
+    "login",
+    "p",
+    "play",
+    "promo",
+    "register",
+    "rewards",
+    "search",
+    "seller",
+    "top-up",
+    "topads",
+    "user",
+];
+/// Second path segments of shop pages that are not products.
+const SHOP_PAGES: [&str; 9] = [
+    "campaign", "etalase", "feed", "home", "info", "note", "product", "review", "showcase",
+];
+
+/// Follows short link redirects only while they stay on Tokopedia hosts, so a
+/// share link can't point us at an arbitrary server.
+static RESOLVE_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(20))
+        .redirect(Policy::custom(|attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS || !is_trusted(attempt.url()) {
+                return attempt.stop();
+            }
+
+            attempt.follow()
+        }))
+        .build()
+        .unwrap()
+});
+
+/// Shop domain and product key, the two parts of a product page path.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductPath {
+    pub shop_domain: String,
+    pub product_key: String,
+}
+
+/// Body of `GET /resolve?url=`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveResponse {
+    pub success: bool,
+    /// `https://www.tokopedia.com/{shopDomain}/{productKey}`
+    pub url: String,
+    #[serde(flatten)]
+    pub path: ProductPath,
+}
+
+/// Turns a product URL or share link into its canonical form.
+pub async fn resolve_url(url: &str) -> ApiResult<ResolveResponse> {
+    let path = resolve(url).await?;
+
+    Ok(ResolveResponse {
+        success: true,
+        url: format!(
+            "{CANONICAL_ORIGIN}{}/{}",
+            path.shop_domain, path.product_key
+        ),
+        path,
+    })
+}
+
+/// Finds the product behind a desktop, mobile or short link URL, following
+/// redirects for the latter.
+pub async fn resolve(url: &str) -> ApiResult<ProductPath> {
+    let url = url.trim();
+    let parsed = parse(url).ok_or_else(|| not_a_product(url))?;
+
+    if !SHORT_LINK_HOSTS.contains(&parsed.host_str().unwrap_or_default()) {
+        return product_path(&parsed).ok_or_else(|| not_a_product(url));
+    }
+
+    let response = RESOLVE_CLIENT
+        .get(parsed)
+        .header("User-Agent", USER_AGENT)
+        .send()
+        .await?
+        .error_for_status()?;
+
+    if let Some(path) = product_path(response.url()) {
+        return Ok(path);
+    }
+
+    // A redirect we refused to follow, or a page off our hosts
+    if !response.status().is_success() || !is_trusted(response.url()) {
+        return Err(not_a_product(url));
+    }
+
+    // Some share links land on an interstitial page that redirects through
+    // JavaScript, so fall back to its canonical URL, then to the first product
+    // URL found in its body.
+    let body = response.text().await?;
+
+    if let Some(path) = canonical_url(&body).and_then(|v| product_path(&v)) {
+        return Ok(path);
+    }
+
+    body.match_indices(CANONICAL_ORIGIN)
+        .filter_map(|(start, _)| {
+            let end = body[start..]
+                .find(|c: char| c == '"' || c == '\'' || c == '<' || c.is_whitespace())
+                .map_or(body.len(), |v| start + v);
+
+            Url::parse(&body[start..end]).ok()
+        })
+        .find_map(|v| product_path(&v))
+        .ok_or_else(|| not_a_product(url))
+}
+
+/// Splits a product page URL into its parts, `None` for anything else.
+pub fn parse_product_url(url: &str) -> Option<ProductPath> {
+    product_path(&Url::parse(url).ok()?)
+}
+
+/// Splits a listing result URL into its product path, unwrapping TopAds click
+/// URLs which carry the product page in their `r` parameter.
+pub fn parse_listing_url(url: &str) -> Option<ProductPath> {
+    parse_product_url(url).or_else(|| {
+        Url::parse(url)
+            .ok()?
+            .query_pairs()
+            .find(|(key, _)| key == "r")
+            .and_then(|(_, value)| parse_product_url(&value))
+    })
+}
+
+/// Parses a URL, assuming `https` when pasted without a scheme.
+fn parse(url: &str) -> Option<Url> {
+    let url = match url.contains("://") {
+        true => Url::parse(url),
+        false => Url::parse(&format!("https://{url}")),
+    }
+    .ok()?;
+
+    matches!(url.scheme(), "http" | "https").then_some(url)
+}
+
+/// Splits a product page URL into its parts, dropping any query string or
+/// fragment (tracking parameters and such).
+fn product_path(url: &Url) -> Option<ProductPath> {
+    if !PRODUCT_HOSTS.contains(&url.host_str().unwrap_or_default()) {
+        return None;
+    }
+
+    match url
+        .path_segments()?
+        .filter(|v| !v.is_empty())
+        .collect::<Vec<_>>()[..]
+    {
+        [shop_domain, product_key]
+            if !RESERVED_PATHS.contains(&shop_domain) && !SHOP_PAGES.contains(&product_key) =>
+        {
+            Some(ProductPath {
+                shop_domain: shop_domain.to_string(),
+                product_key: product_key.to_string(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Whether `url` is on a host we are willing to fetch.
+fn is_trusted(url: &Url) -> bool {
+    let host = url.host_str().unwrap_or_default();
+
+    matches!(url.scheme(), "http" | "https")
+        && (SHORT_LINK_HOSTS.contains(&host) || PRODUCT_HOSTS.contains(&host))
+}
+
+/// The `rel=canonical` or `og:url` URL declared in an HTML page.
+fn canonical_url(body: &str) -> Option<Url> {
+    body.split('<').find_map(|tag| {
+        let value = match tag.split_whitespace().next()? {
+            "link" if attribute(tag, "rel") == Some("canonical") => attribute(tag, "href"),
+            "meta" if attribute(tag, "property") == Some("og:url") => attribute(tag, "content"),
+            _ => None,
+        }?;
+
+        Url::parse(value).ok()
+    })
+}
+
+/// Value of the quoted attribute `name` in the inside of an HTML tag.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+
+    tag.match_indices(name).find_map(|(start, _)| {
+        // Skip matches inside another attribute's name, e.g. `data-rel`
+        if !tag[..start].ends_with(char::is_whitespace) {
+            return None;
+        }
+
+        let rest = tag[start + name.len()..].trim_start().strip_prefix('=')?;
+        let rest = rest.trim_start();
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let rest = &rest[1..];
+
+        Some(&rest[..rest.find(quote)?])
+    })
+}
+
+fn not_a_product(url: &str) -> ApiError {
+    ApiError::BadRequest(format!("`{url}` is not a Tokopedia product URL"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(url: &str) -> Option<(String, String)> {
+        product_path(&parse(url)?).map(|v| (v.shop_domain, v.product_key))
+    }
+
+    fn expected(shop_domain: &str, product_key: &str) -> Option<(String, String)> {
+        Some((shop_domain.to_string(), product_key.to_string()))
+    }
+
+    #[test]
+    fn parses_desktop_and_mobile_urls() {
+        assert_eq!(
+            path("https://www.tokopedia.com/shop/product-key"),
+            expected("shop", "product-key")
+        );
+        assert_eq!(
+            path("https://tokopedia.com/shop/product-key/"),
+            expected("shop", "product-key")
+        );
+        assert_eq!(
+            path("http://m.tokopedia.com/shop/product-key"),
+            expected("shop", "product-key")
+        );
+    }
+
+    #[test]
+    fn parses_urls_without_scheme() {
+        assert_eq!(
+            path("www.tokopedia.com/shop/product-key"),
+            expected("shop", "product-key")
+        );
+        assert_eq!(
+            path("tokopedia.com/shop/product-key"),
+            expected("shop", "product-key")
+        );
+    }
+
+    #[test]
+    fn drops_tracking_query_and_fragment() {
+        assert_eq!(
+            path(
+                "https://www.tokopedia.com/shop/product-key?extParam=ivf%3Dfalse&src=topads#review"
+            ),
+            expected("shop", "product-key")
+        );
+    }
+
+    #[test]
+    fn rejects_non_product_urls() {
+        for url in [
+            "https://www.tokopedia.com/shop",
+            "https://www.tokopedia.com/shop/review",
+            "https://www.tokopedia.com/shop/etalase",
+            "https://www.tokopedia.com/shop/etalase/sale",
+            "https://www.tokopedia.com/p/handphone-tablet",
+            "https://www.tokopedia.com/discovery/promo-page",
+            "https://www.tokopedia.com/help/article",
+            "https://www.tokopedia.com/search?q=shop",
+            "https://tokopedia.link/abc123",
+            "https://example.com/shop/product-key",
+            "https://www.tokopedia.com.example.com/shop/product-key",
+            "ftp://www.tokopedia.com/shop/product-key",
+        ] {
+            assert_eq!(path(url), None, "{url}");
+        }
+    }
+
+    #[test]
+    fn unwraps_topads_click_urls() {
+        let url = "https://ta.tokopedia.com/promo/v1/clicks/abc?r=https%3A%2F%2Fwww.tokopedia.com%2Fshop%2Fproduct-key%3Fsrc%3Dtopads";
+
+        assert_eq!(
+            parse_listing_url(url).map(|v| (v.shop_domain, v.product_key)),
+            expected("shop", "product-key")
+        );
+        assert!(parse_listing_url("https://ta.tokopedia.com/promo/v1/clicks/abc").is_none());
+    }
+
+    #[test]
+    fn only_trusts_tokopedia_hosts() {
+        assert!(is_trusted(&parse("https://tokopedia.link/abc123").unwrap()));
+        assert!(is_trusted(&parse("https://www.tokopedia.com/").unwrap()));
+        assert!(!is_trusted(&parse("http://127.0.0.1/").unwrap()));
+        assert!(!is_trusted(
+            &parse("https://tokopedia.link.example.com/").unwrap()
+        ));
+    }
+
+    #[test]
+    fn prefers_canonical_url() {
+        let body = r#"<html><head>
+            <meta data-property="og:url" content="https://www.tokopedia.com/other/decoy">
+            <meta property="og:url" content="https://www.tokopedia.com/shop/product-key?src=share">
+            </head><body><a href="https://www.tokopedia.com/other/first-link">x</a></body></html>"#;
+
+        let url = canonical_url(body).unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "https://www.tokopedia.com/shop/product-key?src=share"
+        );
+
+        let body = r#"<link href='https://www.tokopedia.com/shop/product-key' rel='canonical'/>"#;
+
+        assert_eq!(
+            product_path(&canonical_url(body).unwrap()).map(|v| v.product_key),
+            Some("product-key".to_string())
+        );
+        assert!(canonical_url("<p>no canonical here</p>").is_none());
+    }
+}
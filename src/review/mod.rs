@@ -0,0 +1,191 @@
+mod model;
+mod options;
+mod upstream;
+
+use serde_json::json;
+
+use crate::{date::unix_timestamp, error::ApiResult, gql::Operation, lookup, query::Query};
+
+use model::{RatingBucket, Review, ReviewImage, ReviewTopic, Reviewer, SellerReply};
+pub use model::{ReviewSummaryResponse, ReviewsResponse};
+use options::ReviewOptions;
+use upstream::{ProductRatingAndTopic, ProductReviewList, RatingDetail};
+
+const LIST_OPERATION: Operation = Operation {
+    name: "productReviewList",
+    query: include_str!("list.graphql"),
+    akamai: None,
+};
+
+const SUMMARY_OPERATION: Operation = Operation {
+    name: "productRatingAndTopic",
+    query: include_str!("summary.graphql"),
+    akamai: None,
+};
+
+pub async fn reviews(seller: &str, product: &str, query: &Query) -> ApiResult<ReviewsResponse> {
+    let options = ReviewOptions::from_query(query)?;
+    let product_id = lookup::product_id(seller, product).await?;
+
+    let response = LIST_OPERATION
+        .fetch(json!({
+            "productID": product_id,
+            "page": options.page,
+            "limit": options.limit,
+            "sortBy": options.sort.sort_by(),
+            "filterBy": options.filter_by()
+        }))
+        .await?;
+
+    let list = LIST_OPERATION
+        .parse::<ProductReviewList>(&response)?
+        .productrev_get_product_review_list;
+
+    let results = list
+        .list
+        .into_iter()
+        .map(|review| Review {
+            id: review.id,
+            reviewer: Reviewer {
+                name: review.user.full_name,
+                avatar: review.user.image,
+                is_anonymous: review.is_anonymous,
+            },
+            rating: review.product_rating,
+            message: review.message,
+            variant: review.variant_name,
+            created_at: unix_timestamp(&review.review_create_timestamp),
+            created_at_text: review.review_create_time,
+            images: review
+                .image_attachments
+                .into_iter()
+                .map(|v| ReviewImage {
+                    thumbnail: v.image_thumbnail_url,
+                    original: v.image_url,
+                })
+                .collect(),
+            videos: review
+                .video_attachments
+                .into_iter()
+                .map(|v| v.video_url)
+                .collect(),
+            seller_reply: review
+                .review_response
+                .filter(|v| !v.message.is_empty())
+                .map(|v| SellerReply {
+                    message: v.message,
+                    created_at_text: v.create_time,
+                }),
+            likes: review.like_dislike.map_or(0, |v| v.total_like),
+        })
+        .collect();
+
+    Ok(ReviewsResponse {
+        success: true,
+        product_id,
+        page: options.page,
+        limit: options.limit,
+        sort: options.sort.name(),
+        total_data: list.total_reviews,
+        next_page: list.has_next.then_some(options.page + 1),
+        results,
+    })
+}
+
+pub async fn summary(seller: &str, product: &str) -> ApiResult<ReviewSummaryResponse> {
+    let product_id = lookup::product_id(seller, product).await?;
+
+    let response = SUMMARY_OPERATION
+        .fetch(json!({ "productID": product_id }))
+        .await?;
+
+    let summary = SUMMARY_OPERATION
+        .parse::<ProductRatingAndTopic>(&response)?
+        .productrev_get_product_rating_and_topics;
+    let rating = summary.rating;
+
+    Ok(ReviewSummaryResponse {
+        success: true,
+        product_id,
+        average: rating.rating_score,
+        total_ratings: rating.total_rating,
+        total_reviews: rating.total_rating_text_and_image,
+        total_with_media: rating.total_rating_with_image,
+        satisfied_percentage: percentage(&rating.positive_percentage_fmt),
+        satisfied_text: rating.positive_percentage_fmt,
+        histogram: histogram(&rating.detail),
+        topics: summary
+            .topics
+            .into_iter()
+            .filter(|v| v.show)
+            .map(|v| ReviewTopic {
+                key: v.key,
+                name: v.formatted,
+                rating: v.rating,
+                review_count: v.review_count,
+            })
+            .collect(),
+    })
+}
+
+/// One bucket per star from 5 down to 1, zero for stars the upstream left
+/// out and the first entry for stars it sent twice.
+fn histogram(detail: &[RatingDetail]) -> Vec<RatingBucket> {
+    (1..=5)
+        .rev()
+        .map(|stars| {
+            let bucket = detail.iter().find(|v| v.rate == stars);
+
+            RatingBucket {
+                stars,
+                count: bucket.map_or(0, |v| v.total_reviews),
+                percentage: bucket.map_or(0.0, |v| v.percentage_float),
+            }
+        })
+        .collect()
+}
+
+/// Reads the leading figure of a formatted percentage such as
+/// `98,5% pembeli merasa puas`.
+fn percentage(text: &str) -> Option<f64> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let figure = text[start..]
+        .split(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+        .next()?;
+
+    figure.replace(',', ".").parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_satisfied_percentage() {
+        assert_eq!(percentage("98% pembeli merasa puas"), Some(98.0));
+        assert_eq!(percentage("98,5% pembeli merasa puas"), Some(98.5));
+        assert_eq!(percentage("100%"), Some(100.0));
+        assert_eq!(percentage(""), None);
+    }
+
+    #[test]
+    fn histogram_has_every_star_once() {
+        let detail = |rate, total_reviews| RatingDetail {
+            rate,
+            total_reviews,
+            percentage_float: total_reviews as f64,
+        };
+
+        let histogram = histogram(&[detail(1, 10), detail(5, 60), detail(5, 99)]);
+
+        assert_eq!(
+            histogram
+                .iter()
+                .map(|v| (v.stars, v.count))
+                .collect::<Vec<_>>(),
+            [(5, 60), (4, 0), (3, 0), (2, 0), (1, 10)]
+        );
+        assert_eq!(histogram[0].percentage, 60.0);
+        assert_eq!(histogram[1].percentage, 0.0);
+    }
+}
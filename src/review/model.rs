@@ -0,0 +1,100 @@
+use serde::Serialize;
+
+/// Body of `GET /lookup/{seller}/{product}/reviews`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewsResponse {
+    pub success: bool,
+    pub product_id: String,
+    pub page: u32,
+    pub limit: u32,
+    pub sort: &'static str,
+    /// Number of reviews matching the filters
+    pub total_data: u64,
+    /// Page to request next, `null` on the last page
+    pub next_page: Option<u32>,
+    pub results: Vec<Review>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Review {
+    pub id: String,
+    pub reviewer: Reviewer,
+    /// Star rating, 1 to 5
+    pub rating: u8,
+    pub message: String,
+    /// Variant the reviewer bought, empty for single-SKU products
+    pub variant: String,
+    /// Unix timestamp, `null` when the upstream did not send one
+    pub created_at: Option<i64>,
+    /// Relative upstream wording, e.g. `1 bulan lalu`
+    pub created_at_text: String,
+    pub images: Vec<ReviewImage>,
+    /// Video URLs
+    pub videos: Vec<String>,
+    pub seller_reply: Option<SellerReply>,
+    pub likes: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reviewer {
+    /// Masked by the upstream for anonymous reviews
+    pub name: String,
+    pub avatar: String,
+    pub is_anonymous: bool,
+}
+
+#[derive(Serialize)]
+pub struct ReviewImage {
+    pub thumbnail: String,
+    pub original: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SellerReply {
+    pub message: String,
+    /// Relative upstream wording
+    pub created_at_text: String,
+}
+
+/// Body of `GET /lookup/{seller}/{product}/reviews/summary`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewSummaryResponse {
+    pub success: bool,
+    pub product_id: String,
+    /// Average rating, out of 5
+    pub average: f64,
+    pub total_ratings: u64,
+    /// Ratings that come with a written review
+    pub total_reviews: u64,
+    pub total_with_media: u64,
+    /// Share of buyers who were satisfied, `null` when the upstream sent none
+    pub satisfied_percentage: Option<f64>,
+    /// Upstream wording, e.g. `98% pembeli merasa puas`
+    pub satisfied_text: String,
+    /// One entry per star, from 5 down to 1
+    pub histogram: Vec<RatingBucket>,
+    /// Topics buyers mention the most, as shown on the product page
+    pub topics: Vec<ReviewTopic>,
+}
+
+#[derive(Serialize)]
+pub struct RatingBucket {
+    pub stars: u8,
+    pub count: u64,
+    pub percentage: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewTopic {
+    pub key: String,
+    pub name: String,
+    /// Average rating of the reviews mentioning this topic
+    pub rating: f64,
+    pub review_count: u64,
+}
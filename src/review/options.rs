@@ -0,0 +1,107 @@
+use crate::{
+    error::{ApiError, ApiResult},
+    query::Query,
+};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 50;
+
+/// Review ordering, mapped onto the upstream `sortBy` parameter.
+#[derive(Clone, Copy)]
+pub enum ReviewSort {
+    MostHelpful,
+    Newest,
+    HighestRating,
+    LowestRating,
+}
+
+impl ReviewSort {
+    /// The first entry is the default
+    const ALL: [ReviewSort; 4] = [
+        ReviewSort::MostHelpful,
+        ReviewSort::Newest,
+        ReviewSort::HighestRating,
+        ReviewSort::LowestRating,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReviewSort::MostHelpful => "most_helpful",
+            ReviewSort::Newest => "newest",
+            ReviewSort::HighestRating => "highest_rating",
+            ReviewSort::LowestRating => "lowest_rating",
+        }
+    }
+
+    pub fn sort_by(&self) -> &'static str {
+        match self {
+            ReviewSort::MostHelpful => "informative_score desc",
+            ReviewSort::Newest => "create_time desc",
+            ReviewSort::HighestRating => "rating_score desc",
+            ReviewSort::LowestRating => "rating_score asc",
+        }
+    }
+}
+
+/// Review list parameters taken from the request query string.
+pub struct ReviewOptions {
+    pub page: u32,
+    pub limit: u32,
+    pub sort: ReviewSort,
+    /// Star ratings to keep, all of them when empty
+    pub ratings: Vec<u8>,
+    /// Only reviews with images or videos attached
+    pub with_media: bool,
+}
+
+impl ReviewOptions {
+    pub fn from_query(query: &Query) -> ApiResult<Self> {
+        let (page, limit) = query.page_and_size("limit", DEFAULT_LIMIT, MAX_LIMIT)?;
+
+        let invalid_rating = || {
+            ApiError::BadRequest(
+                "`rating` must be a comma separated list of stars from 1 to 5".to_string(),
+            )
+        };
+
+        let mut ratings = match query.get("rating") {
+            Some(value) => value
+                .split(',')
+                .map(|v| v.trim().parse::<u8>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| invalid_rating())?,
+            None => Vec::new(),
+        };
+
+        if ratings.iter().any(|v| !(1..=5).contains(v)) {
+            return Err(invalid_rating());
+        }
+
+        ratings.sort_unstable_by(|a, b| b.cmp(a));
+        ratings.dedup();
+
+        Ok(Self {
+            page,
+            limit,
+            sort: query.choice("sort", &ReviewSort::ALL, ReviewSort::name)?,
+            ratings,
+            with_media: query.flag("media")?,
+        })
+    }
+
+    /// The upstream `filterBy` parameter, e.g. `rating=5,4;withAttachment=1`.
+    pub fn filter_by(&self) -> String {
+        let mut filters = Vec::new();
+
+        if !self.ratings.is_empty() {
+            let ratings = self.ratings.iter().map(|v| v.to_string());
+            filters.push(format!("rating={}", ratings.collect::<Vec<_>>().join(",")));
+        }
+
+        if self.with_media {
+            filters.push("withAttachment=1".to_string());
+        }
+
+        filters.join(";")
+    }
+}
@@ -0,0 +1,136 @@
+use serde::Deserialize;
+
+use crate::de;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductReviewList {
+    pub productrev_get_product_review_list: ReviewList,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewList {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub list: Vec<Review>,
+    pub has_next: bool,
+    #[serde(deserialize_with = "de::number")]
+    pub total_reviews: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Review {
+    #[serde(deserialize_with = "de::string")]
+    pub id: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub variant_name: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub message: String,
+    #[serde(deserialize_with = "de::number")]
+    pub product_rating: u8,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub review_create_time: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub review_create_timestamp: String,
+    pub is_anonymous: bool,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub image_attachments: Vec<ImageAttachment>,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub video_attachments: Vec<VideoAttachment>,
+    pub review_response: Option<ReviewResponse>,
+    pub user: User,
+    pub like_dislike: Option<LikeDislike>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageAttachment {
+    pub image_thumbnail_url: String,
+    pub image_url: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoAttachment {
+    pub video_url: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewResponse {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub message: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub create_time: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub full_name: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub image: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LikeDislike {
+    #[serde(deserialize_with = "de::number")]
+    pub total_like: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductRatingAndTopic {
+    pub productrev_get_product_rating_and_topics: RatingAndTopics,
+}
+
+#[derive(Deserialize)]
+pub struct RatingAndTopics {
+    pub rating: Rating,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub topics: Vec<Topic>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rating {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub positive_percentage_fmt: String,
+    #[serde(deserialize_with = "de::number")]
+    pub rating_score: f64,
+    #[serde(deserialize_with = "de::number")]
+    pub total_rating: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub total_rating_with_image: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub total_rating_text_and_image: u64,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub detail: Vec<RatingDetail>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingDetail {
+    #[serde(deserialize_with = "de::number")]
+    pub rate: u8,
+    #[serde(deserialize_with = "de::number")]
+    pub total_reviews: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub percentage_float: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Topic {
+    #[serde(deserialize_with = "de::number")]
+    pub rating: f64,
+    pub formatted: String,
+    pub key: String,
+    #[serde(deserialize_with = "de::number")]
+    pub review_count: u64,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub show: bool,
+}
@@ -0,0 +1,133 @@
+mod model;
+mod options;
+mod upstream;
+
+use serde_json::json;
+
+use crate::{error::ApiResult, gql::Operation, query::Query, resolve::parse_listing_url};
+
+use model::{InsteadKeyword, Redirect, RelatedKeyword, RelatedProduct};
+pub use model::{SearchProduct, SearchResponse, Seller};
+use options::SearchOptions;
+use upstream::SearchProductQueryV4;
+
+const OPERATION: Operation = Operation {
+    name: "SearchProductQueryV4",
+    query: include_str!("query.graphql"),
+    akamai: None,
+};
+
+pub async fn search(search_query: &str, query: &Query) -> ApiResult<SearchResponse> {
+    run(SearchOptions::from_query(search_query, query)?).await
+}
+
+/// Browses the listings of a category, with the same query as `search`.
+pub async fn category(category_id: &str, query: &Query) -> ApiResult<SearchResponse> {
+    run(SearchOptions::for_category(category_id, query)?).await
+}
+
+async fn run(options: SearchOptions) -> ApiResult<SearchResponse> {
+    let response = OPERATION
+        .fetch(json!({ "params": options.params() }))
+        .await?;
+
+    let search = OPERATION
+        .parse::<SearchProductQueryV4>(&response)?
+        .ace_search_product_v4;
+    let total_data = search.header.total_data;
+    let data = search.data;
+
+    let mut results = Vec::new();
+
+    for product in data.products {
+        let ad_id = product.ads.id();
+
+        if !options.ads.keeps(ad_id.is_some()) {
+            continue;
+        }
+
+        // One odd hit should not cost the whole page
+        let Some(path) = parse_listing_url(&product.url) else {
+            eprintln!(
+                "Skipping search result with unexpected url `{}`",
+                product.url
+            );
+            continue;
+        };
+
+        results.push(SearchProduct {
+            seller: Seller {
+                name: product.shop.name,
+                id: path.shop_domain,
+                url: product.shop.url,
+                city: product.shop.city,
+                is_official: product.shop.is_official,
+                has_power_badge: product.shop.is_power_badge,
+            },
+            name: product.name,
+            url: product.url,
+            price: product.price,
+            thumbnail: product.image_url,
+            category: Some(product.category_name).filter(|v| !v.is_empty()),
+            category_id: category_id(product.category_id),
+            sponsored: ad_id.is_some(),
+            ad_id,
+            id: path.product_key,
+        });
+    }
+
+    let suggestion = data.suggestion;
+    let redirection = data.redirection;
+
+    let related_keyword = data.related.related_keyword;
+    let related = data
+        .related
+        .other_related
+        .into_iter()
+        .map(|related| RelatedKeyword {
+            keyword: related.keyword,
+            url: related.url,
+            products: related
+                .product
+                .into_iter()
+                .map(|v| RelatedProduct {
+                    id: v.id,
+                    name: v.name,
+                    url: v.url,
+                    price: v.price_str,
+                    thumbnail: v.image_url,
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(SearchResponse {
+        success: true,
+        keyword: suggestion.current_keyword,
+        suggestion: suggestion.suggestion,
+        instead: (!suggestion.instead.is_empty()).then_some(InsteadKeyword {
+            keyword: suggestion.instead,
+            count: suggestion.instead_count,
+        }),
+        redirect: (!redirection.redirect_url.is_empty()).then_some(Redirect {
+            url: redirection.redirect_url,
+            category_id: redirection.department_id,
+        }),
+        related_keyword,
+        related,
+        page: options.page,
+        rows: options.rows,
+        sort: options.sort.name(),
+        ads: options.ads.name(),
+        backend_filters: data.backend_filters,
+        total_data,
+        next_page: options.next_page(total_data),
+        category_id: options.category,
+        results,
+    })
+}
+
+/// An upstream category ID, `None` when it is empty or `0`.
+pub fn category_id(id: String) -> Option<String> {
+    Some(id).filter(|v| !v.is_empty() && v != "0")
+}
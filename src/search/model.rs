@@ -0,0 +1,104 @@
+use serde::Serialize;
+
+/// Body of `GET /search/{query}`, `GET /search?q=` and
+/// `GET /categories/{id}/products`, see `SearchOptions` for the accepted query.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResponse {
+    pub success: bool,
+    /// Keyword the upstream actually searched for, empty when browsing a
+    /// category
+    pub keyword: String,
+    /// Category being browsed, `null` for keyword searches
+    pub category_id: Option<String>,
+    /// Spelling suggestion, empty when there is none
+    pub suggestion: String,
+    /// Keyword the upstream searched for instead of the requested one,
+    /// `null` when it kept the requested keyword
+    pub instead: Option<InsteadKeyword>,
+    /// Set when Tokopedia would send the user to a category page instead
+    pub redirect: Option<Redirect>,
+    /// Headline related keyword, empty when there is none
+    pub related_keyword: String,
+    /// Related keywords, each with a few preview products
+    pub related: Vec<RelatedKeyword>,
+    pub page: u32,
+    pub rows: u32,
+    pub sort: &'static str,
+    /// Which results were kept relative to TopAds, see `AdsFilter`
+    pub ads: &'static str,
+    /// Filters the upstream applied, in its own query string form
+    pub backend_filters: String,
+    /// Number of results across all pages
+    pub total_data: u64,
+    /// Page to request next, `null` on the last page
+    pub next_page: Option<u32>,
+    pub results: Vec<SearchProduct>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchProduct {
+    pub seller: Seller,
+    pub name: String,
+    pub url: String,
+    /// Formatted price, e.g. `Rp12.500`
+    pub price: String,
+    pub thumbnail: String,
+    /// Category name, `null` for shop listings whose upstream only sends the ID
+    pub category: Option<String>,
+    /// Usable as `/categories/{categoryId}/products`, `null` when unknown
+    pub category_id: Option<String>,
+    /// Whether this is a paid TopAds placement rather than an organic hit,
+    /// always `false` for shop listings which carry no ads
+    pub sponsored: bool,
+    /// TopAds ID, `null` for organic results
+    pub ad_id: Option<String>,
+    /// Product key, usable as `/lookup/{seller.id}/{id}`
+    pub id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Seller {
+    pub name: String,
+    /// Shop domain
+    pub id: String,
+    pub url: String,
+    pub city: String,
+    pub is_official: bool,
+    pub has_power_badge: bool,
+}
+
+#[derive(Serialize)]
+pub struct InsteadKeyword {
+    pub keyword: String,
+    /// Number of results for `keyword`
+    pub count: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Redirect {
+    pub url: String,
+    /// Usable as `/categories/{categoryId}/products`, empty when unknown
+    pub category_id: String,
+}
+
+#[derive(Serialize)]
+pub struct RelatedKeyword {
+    pub keyword: String,
+    pub url: String,
+    pub products: Vec<RelatedProduct>,
+}
+
+#[derive(Serialize)]
+pub struct RelatedProduct {
+    /// Numeric product ID
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    /// Formatted price, e.g. `Rp12.500`
+    pub price: String,
+    pub thumbnail: String,
+}
@@ -0,0 +1,359 @@
+use url::form_urlencoded;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    query::Query,
+};
+
+const MAX_KEYWORD_LENGTH: usize = 100;
+const DEFAULT_ROWS: u32 = 20;
+const MAX_ROWS: u32 = 100;
+
+/// Result ordering, mapped onto the upstream `ob` parameter.
+#[derive(Clone, Copy)]
+pub enum Sort {
+    Relevance,
+    Newest,
+    PriceAsc,
+    PriceDesc,
+    Rating,
+    MostReviewed,
+}
+
+impl Sort {
+    /// The first entry is the default
+    const ALL: [Sort; 6] = [
+        Sort::Relevance,
+        Sort::Newest,
+        Sort::PriceAsc,
+        Sort::PriceDesc,
+        Sort::Rating,
+        Sort::MostReviewed,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Sort::Relevance => "relevance",
+            Sort::Newest => "newest",
+            Sort::PriceAsc => "price_asc",
+            Sort::PriceDesc => "price_desc",
+            Sort::Rating => "rating",
+            Sort::MostReviewed => "most_reviewed",
+        }
+    }
+
+    pub fn ob(&self) -> u8 {
+        match self {
+            Sort::Relevance => 23,
+            Sort::Newest => 9,
+            Sort::PriceAsc => 3,
+            Sort::PriceDesc => 4,
+            Sort::Rating => 5,
+            Sort::MostReviewed => 8,
+        }
+    }
+}
+
+/// Which results to keep relative to TopAds placements. The upstream has no
+/// such parameter, so results are dropped after the fetch and a page may hold
+/// fewer than `rows` results.
+#[derive(Clone, Copy)]
+pub enum AdsFilter {
+    Include,
+    Exclude,
+    Only,
+}
+
+impl AdsFilter {
+    /// The first entry is the default
+    const ALL: [AdsFilter; 3] = [AdsFilter::Include, AdsFilter::Exclude, AdsFilter::Only];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AdsFilter::Include => "include",
+            AdsFilter::Exclude => "exclude",
+            AdsFilter::Only => "only",
+        }
+    }
+
+    /// Whether a result is kept, given whether it is sponsored.
+    pub fn keeps(&self, sponsored: bool) -> bool {
+        match self {
+            AdsFilter::Include => true,
+            AdsFilter::Exclude => !sponsored,
+            AdsFilter::Only => sponsored,
+        }
+    }
+}
+
+/// Product condition, the upstream `condition` parameter.
+#[derive(Clone, Copy)]
+pub enum Condition {
+    New,
+    Used,
+}
+
+/// Optional result filters, forwarded to the upstream as-is once validated.
+pub struct SearchFilters {
+    pub price_min: Option<u64>,
+    pub price_max: Option<u64>,
+    /// Upstream city IDs
+    pub cities: Vec<u32>,
+    pub official: bool,
+    pub power_merchant: bool,
+    pub condition: Option<Condition>,
+    /// Minimum product rating, 1 to 5
+    pub rating: Option<u8>,
+}
+
+impl SearchFilters {
+    fn from_query(query: &Query) -> ApiResult<Self> {
+        let price_min = query.optional_number("pmin")?;
+        let price_max = query.optional_number("pmax")?;
+
+        if let (Some(min), Some(max)) = (price_min, price_max) {
+            if min > max {
+                return Err(ApiError::BadRequest(
+                    "`pmin` must not be greater than `pmax`".to_string(),
+                ));
+            }
+        }
+
+        let cities = match query.get("fcity") {
+            Some(value) => value
+                .split(',')
+                .map(|v| v.trim().parse())
+                .collect::<Result<Vec<u32>, _>>()
+                .map_err(|_| {
+                    ApiError::BadRequest(
+                        "`fcity` must be a comma separated list of city IDs".to_string(),
+                    )
+                })?,
+            None => Vec::new(),
+        };
+
+        let condition = match query.get("condition") {
+            None => None,
+            Some("new") => Some(Condition::New),
+            Some("used") => Some(Condition::Used),
+            Some(_) => {
+                return Err(ApiError::BadRequest(
+                    "`condition` must be one of: new, used".to_string(),
+                ))
+            }
+        };
+
+        let rating = query.optional_number("rating")?;
+
+        if matches!(rating, Some(rating) if !(1..=5).contains(&rating)) {
+            return Err(ApiError::BadRequest(
+                "`rating` must be between 1 and 5".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            price_min,
+            price_max,
+            cities,
+            official: query.flag("official")?,
+            power_merchant: query.flag("goldmerchant")?,
+            condition,
+            rating,
+        })
+    }
+
+    /// Upstream `params` pairs for the filters that are set.
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        if let Some(price_min) = self.price_min {
+            params.push(("pmin", price_min.to_string()));
+        }
+
+        if let Some(price_max) = self.price_max {
+            params.push(("pmax", price_max.to_string()));
+        }
+
+        if !self.cities.is_empty() {
+            let cities = self.cities.iter().map(|v| v.to_string());
+            params.push(("fcity", cities.collect::<Vec<_>>().join(",")));
+        }
+
+        if self.official {
+            params.push(("official", "true".to_string()));
+        }
+
+        if self.power_merchant {
+            params.push(("goldmerchant", "true".to_string()));
+        }
+
+        if let Some(condition) = self.condition {
+            let condition = match condition {
+                Condition::New => "1",
+                Condition::Used => "2",
+            };
+            params.push(("condition", condition.to_string()));
+        }
+
+        if let Some(rating) = self.rating {
+            let ratings = (rating..=5).map(|v| v.to_string());
+            params.push(("rt", ratings.collect::<Vec<_>>().join(",")));
+        }
+
+        params
+    }
+}
+
+/// Search parameters taken from the request query string.
+pub struct SearchOptions {
+    pub keyword: String,
+    /// Category to browse, set instead of `keyword`
+    pub category: Option<String>,
+    pub page: u32,
+    pub rows: u32,
+    pub sort: Sort,
+    pub ads: AdsFilter,
+    pub filters: SearchFilters,
+}
+
+impl SearchOptions {
+    pub fn from_query(keyword: &str, query: &Query) -> ApiResult<Self> {
+        let keyword = keyword.trim();
+
+        if keyword.is_empty() {
+            return Err(ApiError::BadRequest(
+                "Search query must not be empty".to_string(),
+            ));
+        }
+
+        if keyword.chars().count() > MAX_KEYWORD_LENGTH {
+            return Err(ApiError::BadRequest(format!(
+                "Search query must be at most {MAX_KEYWORD_LENGTH} characters"
+            )));
+        }
+
+        Self::build(keyword.to_string(), None, query)
+    }
+
+    /// Options for browsing the category `category_id`, without a keyword.
+    pub fn for_category(category_id: &str, query: &Query) -> ApiResult<Self> {
+        if category_id.is_empty() || !category_id.bytes().all(|v| v.is_ascii_digit()) {
+            return Err(ApiError::BadRequest(
+                "Category ID must be numeric".to_string(),
+            ));
+        }
+
+        Self::build("".to_string(), Some(category_id.to_string()), query)
+    }
+
+    fn build(keyword: String, category: Option<String>, query: &Query) -> ApiResult<Self> {
+        let (page, rows) = query.page_and_size("rows", DEFAULT_ROWS, MAX_ROWS)?;
+
+        Ok(Self {
+            keyword,
+            category,
+            page,
+            rows,
+            sort: query.choice("sort", &Sort::ALL, Sort::name)?,
+            ads: query.choice("ads", &AdsFilter::ALL, AdsFilter::name)?,
+            filters: SearchFilters::from_query(query)?,
+        })
+    }
+
+    /// The upstream `params` string.
+    pub fn params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+
+        if let Some(category) = &self.category {
+            params.append_pair("sc", category);
+        }
+
+        params
+            .append_pair("device", "desktop")
+            .append_pair("navsource", "home")
+            .append_pair("ob", &self.sort.ob().to_string())
+            .append_pair("page", &self.page.to_string())
+            .append_pair("q", &self.keyword)
+            .append_pair("related", "true")
+            .append_pair("rows", &self.rows.to_string())
+            .append_pair("safe_search", "false")
+            .append_pair("scheme", "https")
+            .append_pair("shipping", "")
+            .append_pair(
+                "source",
+                match self.category {
+                    Some(_) => "directory",
+                    None => "universe",
+                },
+            )
+            .append_pair("st", "product")
+            .append_pair("start", &self.start().to_string())
+            .append_pair("topads_bucket", "true")
+            .extend_pairs(self.filters.params())
+            .finish()
+    }
+
+    /// Offset of the first result on this page.
+    pub fn start(&self) -> u64 {
+        (self.page as u64 - 1) * self.rows as u64
+    }
+
+    /// The following page, if `total_data` has more results past this one.
+    pub fn next_page(&self, total_data: u64) -> Option<u32> {
+        (self.start() + (self.rows as u64) < total_data).then_some(self.page + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(keyword: &str, query: &str) -> Vec<(String, String)> {
+        let options = SearchOptions::from_query(keyword, &Query::parse(Some(query)))
+            .ok()
+            .unwrap();
+
+        form_urlencoded::parse(options.params().as_bytes())
+            .into_owned()
+            .collect()
+    }
+
+    fn values<'a>(params: &'a [(String, String)], key: &str) -> Vec<&'a str> {
+        params
+            .iter()
+            .filter(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn keyword_is_a_single_parameter() {
+        let params = params("a&rows=1000&safe_search=true", "");
+
+        assert_eq!(values(&params, "q"), ["a&rows=1000&safe_search=true"]);
+        assert_eq!(values(&params, "rows"), ["20"]);
+        assert_eq!(values(&params, "safe_search"), ["false"]);
+    }
+
+    #[test]
+    fn keyword_keeps_reserved_characters() {
+        let params = params("50% off #1", "rows=5");
+
+        assert_eq!(values(&params, "q"), ["50% off #1"]);
+        assert_eq!(values(&params, "rows"), ["5"]);
+    }
+
+    #[test]
+    fn rejects_empty_and_long_keywords() {
+        let query = Query::parse(None);
+
+        for keyword in ["", "   ", &"a".repeat(MAX_KEYWORD_LENGTH + 1)] {
+            assert!(matches!(
+                SearchOptions::from_query(keyword, &query),
+                Err(ApiError::BadRequest(_))
+            ));
+        }
+
+        assert!(SearchOptions::from_query(&"a".repeat(MAX_KEYWORD_LENGTH), &query).is_ok());
+    }
+}
@@ -0,0 +1,123 @@
+use serde::Deserialize;
+
+use crate::de;
+
+#[derive(Deserialize)]
+pub struct SearchProductQueryV4 {
+    pub ace_search_product_v4: AceSearchProduct,
+}
+
+#[derive(Deserialize)]
+pub struct AceSearchProduct {
+    pub header: SearchHeader,
+    pub data: SearchData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHeader {
+    pub total_data: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchData {
+    pub backend_filters: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub redirection: Redirection,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub related: Related,
+    pub suggestion: Suggestion,
+    pub products: Vec<Product>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Suggestion {
+    pub current_keyword: String,
+    pub suggestion: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub instead: String,
+    #[serde(default, deserialize_with = "de::number")]
+    pub instead_count: u64,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Redirection {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub redirect_url: String,
+    #[serde(default, deserialize_with = "de::string")]
+    pub department_id: String,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Related {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub related_keyword: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub other_related: Vec<OtherRelated>,
+}
+
+#[derive(Deserialize)]
+pub struct OtherRelated {
+    pub keyword: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub url: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub product: Vec<RelatedProduct>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelatedProduct {
+    #[serde(deserialize_with = "de::string")]
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub price_str: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub image_url: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Product {
+    pub name: String,
+    pub url: String,
+    pub price: String,
+    pub image_url: String,
+    pub category_name: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub ads: Ads,
+    #[serde(deserialize_with = "de::string")]
+    pub category_id: String,
+    pub shop: Shop,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Shop {
+    pub name: String,
+    pub url: String,
+    pub city: String,
+    pub is_official: bool,
+    pub is_power_badge: bool,
+}
+
+/// TopAds placement of a result, with an empty or `0` ID for organic ones.
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Ads {
+    #[serde(default, deserialize_with = "de::string")]
+    pub ads_id: String,
+}
+
+impl Ads {
+    /// The ad ID, `None` for organic results.
+    pub fn id(self) -> Option<String> {
+        Some(self.ads_id).filter(|v| !v.is_empty() && v != "0")
+    }
+}
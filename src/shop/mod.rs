@@ -0,0 +1,228 @@
+mod model;
+mod options;
+mod upstream;
+
+use serde_json::json;
+
+use crate::{
+    date::unix_timestamp,
+    error::{ApiError, ApiResult},
+    gql::Operation,
+    query::Query,
+    resolve::parse_listing_url,
+    search::{category_id, SearchProduct, Seller},
+};
+
+use model::{Etalase, Reputation, ResponseSpeed, ShopLocation, ShopRating, ShopStatus};
+pub use model::{EtalaseResponse, ShopProductsResponse, ShopProfile};
+use options::ShopProductsOptions;
+use upstream::{ShopInfo, ShopInfoCore, ShopProducts, ShopShowcasesByShopId, ShopStatisticQuery};
+
+const INFO_OPERATION: Operation = Operation {
+    name: "ShopInfoCore",
+    query: include_str!("info.graphql"),
+    akamai: None,
+};
+
+const STATISTIC_OPERATION: Operation = Operation {
+    name: "ShopStatisticQuery",
+    query: include_str!("statistic.graphql"),
+    akamai: None,
+};
+
+const PRODUCTS_OPERATION: Operation = Operation {
+    name: "ShopProducts",
+    query: include_str!("products.graphql"),
+    akamai: None,
+};
+
+const ETALASE_OPERATION: Operation = Operation {
+    name: "shopShowcasesByShopID",
+    query: include_str!("etalase.graphql"),
+    akamai: None,
+};
+
+/// Fetches the core info of the shop at `domain`.
+async fn shop_info(domain: &str) -> ApiResult<ShopInfo> {
+    let response = INFO_OPERATION
+        .fetch(json!({ "id": 0, "domain": domain }))
+        .await?;
+
+    INFO_OPERATION
+        .parse::<ShopInfoCore>(&response)?
+        .shop_info_by_id
+        .result
+        .into_iter()
+        .find(|v| !v.shop_core.shop_id.is_empty() && v.shop_core.shop_id != "0")
+        .ok_or(ApiError::NotFound("Shop not found"))
+}
+
+pub async fn profile(domain: &str) -> ApiResult<ShopProfile> {
+    let info = shop_info(domain).await?;
+    let shop_id = &info.shop_core.shop_id;
+
+    let response = STATISTIC_OPERATION
+        .fetch(json!({
+            "shopID": shop_id
+                .parse::<u64>()
+                .map_err(|_| ApiError::shape(format!("unexpected shop ID `{shop_id}`")))?,
+            "shopIDStr": shop_id
+        }))
+        .await?;
+    let statistic = STATISTIC_OPERATION.parse::<ShopStatisticQuery>(&response)?;
+
+    let city = info.city();
+
+    Ok(ShopProfile {
+        success: true,
+        url: format!("https://www.tokopedia.com/{}", info.shop_core.domain),
+        id: info.shop_core.shop_id,
+        name: info.shop_core.name,
+        domain: info.shop_core.domain,
+        description: info.shop_core.description,
+        tagline: info.shop_core.tag_line,
+        location: ShopLocation {
+            city,
+            district: info.shipping_loc.district_name,
+        },
+        joined_at: unix_timestamp(&info.create_info.open_since),
+        joined_at_text: info.create_info.open_since,
+        is_official: info.gold_os.is_official,
+        is_power_merchant: info.gold_os.is_gold,
+        merchant_badge: info.gold_os.badge,
+        reputation: statistic
+            .shop_reputation
+            .into_iter()
+            .next()
+            .map(|v| Reputation {
+                badge: v.badge,
+                score: v.score,
+            }),
+        rating: statistic.shop_rating.map(|v| ShopRating {
+            score: v.rating_score,
+            total_ratings: v.total_rating,
+        }),
+        total_products: info.active_product,
+        total_sold: info.shop_stats.product_sold,
+        total_transactions: info.shop_stats.total_tx_success,
+        total_showcases: info.shop_stats.total_showcase,
+        response_speed: statistic.shop_speed.map(|v| ResponseSpeed {
+            text: v.message_response_time,
+            hours: v.hour,
+        }),
+        status: ShopStatus {
+            is_open: info.status_info.shop_status == 1,
+            message: info.status_info.status_message,
+            closed_note: info.closed_info.closed_note,
+            closed_reason: info.closed_info.reason,
+            closed_until: info.closed_info.until,
+        },
+        avatar: info.shop_assets.avatar,
+        cover: info.shop_assets.cover,
+    })
+}
+
+pub async fn products(domain: &str, query: &Query) -> ApiResult<ShopProductsResponse> {
+    let options = ShopProductsOptions::from_query(query)?;
+    let info = shop_info(domain).await?;
+
+    let response = PRODUCTS_OPERATION
+        .fetch(json!({
+            "sid": info.shop_core.shop_id,
+            "page": options.page,
+            "perPage": options.per_page,
+            "keyword": "",
+            "etalaseId": options.etalase.as_deref().unwrap_or("etalase"),
+            "sort": options.sort.code()
+        }))
+        .await?;
+
+    let products = PRODUCTS_OPERATION
+        .parse::<ShopProducts>(&response)?
+        .get_shop_product;
+
+    let city = info.city();
+    let shop_url = format!("https://www.tokopedia.com/{}", info.shop_core.domain);
+
+    let mut results = Vec::new();
+
+    for product in products.data {
+        // One odd item should not cost the whole page
+        let Some(path) = parse_listing_url(&product.product_url) else {
+            eprintln!(
+                "Skipping shop product with unexpected url `{}`",
+                product.product_url
+            );
+            continue;
+        };
+
+        results.push(SearchProduct {
+            seller: Seller {
+                name: info.shop_core.name.clone(),
+                id: info.shop_core.domain.clone(),
+                url: shop_url.clone(),
+                city: city.clone(),
+                is_official: info.gold_os.is_official,
+                has_power_badge: info.gold_os.is_gold,
+            },
+            name: product.name,
+            url: product.product_url,
+            price: product.price.text_idr,
+            thumbnail: product.primary_image.resize300,
+            category: None,
+            category_id: category_id(product.category.id),
+            sponsored: false,
+            ad_id: None,
+            id: path.product_key,
+        });
+    }
+
+    Ok(ShopProductsResponse {
+        success: true,
+        page: options.page,
+        per_page: options.per_page,
+        sort: options.sort.name(),
+        next_page: options.next_page(products.total_data),
+        etalase: options.etalase,
+        total_data: products.total_data,
+        results,
+    })
+}
+
+pub async fn etalase(domain: &str) -> ApiResult<EtalaseResponse> {
+    let info = shop_info(domain).await?;
+    let domain = info.shop_core.domain;
+
+    let response = ETALASE_OPERATION
+        .fetch(json!({
+            "shopId": info.shop_core.shop_id,
+            "hideNoCount": true,
+            "hideShowcaseGroup": false,
+            "isOwner": false
+        }))
+        .await?;
+
+    let results = ETALASE_OPERATION
+        .parse::<ShopShowcasesByShopId>(&response)?
+        .shop_showcases_by_shop_id
+        .result
+        .into_iter()
+        .map(|showcase| Etalase {
+            url: match showcase.uri.is_empty() {
+                true => format!(
+                    "https://www.tokopedia.com/{domain}/etalase/{}",
+                    showcase.alias
+                ),
+                false => showcase.uri,
+            },
+            id: showcase.id,
+            name: showcase.name,
+            product_count: showcase.count,
+        })
+        .collect();
+
+    Ok(EtalaseResponse {
+        success: true,
+        results,
+    })
+}
@@ -0,0 +1,108 @@
+use serde::Serialize;
+
+use crate::search::SearchProduct;
+
+/// Body of `GET /shop/{domain}`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopProfile {
+    pub success: bool,
+    pub id: String,
+    pub name: String,
+    pub domain: String,
+    pub url: String,
+    pub description: String,
+    pub tagline: String,
+    pub location: ShopLocation,
+    /// Unix timestamp, `null` when the upstream date could not be read
+    pub joined_at: Option<i64>,
+    /// Join date as sent by the upstream
+    pub joined_at_text: String,
+    pub is_official: bool,
+    pub is_power_merchant: bool,
+    /// Official store or power merchant badge image URL
+    pub merchant_badge: String,
+    pub reputation: Option<Reputation>,
+    pub rating: Option<ShopRating>,
+    pub total_products: u64,
+    pub total_sold: u64,
+    pub total_transactions: u64,
+    pub total_showcases: u64,
+    pub response_speed: Option<ResponseSpeed>,
+    pub status: ShopStatus,
+    pub avatar: String,
+    pub cover: String,
+}
+
+#[derive(Serialize)]
+pub struct ShopLocation {
+    pub city: String,
+    pub district: String,
+}
+
+#[derive(Serialize)]
+pub struct Reputation {
+    /// Badge image URL
+    pub badge: String,
+    pub score: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopRating {
+    /// Average product rating, out of 5
+    pub score: f64,
+    pub total_ratings: u64,
+}
+
+#[derive(Serialize)]
+pub struct ResponseSpeed {
+    /// Upstream wording, e.g. `± 1 jam`
+    pub text: String,
+    pub hours: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopStatus {
+    pub is_open: bool,
+    pub message: String,
+    /// Seller's note while the shop is closed
+    pub closed_note: String,
+    pub closed_reason: String,
+    /// Reopening date while the shop is closed
+    pub closed_until: String,
+}
+
+/// Body of `GET /shop/{domain}/products`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopProductsResponse {
+    pub success: bool,
+    pub page: u32,
+    pub per_page: u32,
+    pub sort: &'static str,
+    pub etalase: Option<String>,
+    /// Number of products across all pages
+    pub total_data: u64,
+    /// Page to request next, `null` on the last page
+    pub next_page: Option<u32>,
+    pub results: Vec<SearchProduct>,
+}
+
+/// Body of `GET /shop/{domain}/etalase`.
+#[derive(Serialize)]
+pub struct EtalaseResponse {
+    pub success: bool,
+    pub results: Vec<Etalase>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Etalase {
+    /// Pass as `?etalase=` to `/shop/{domain}/products`
+    pub id: String,
+    pub name: String,
+    pub product_count: u64,
+    pub url: String,
+}
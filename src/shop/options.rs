@@ -0,0 +1,90 @@
+use crate::{
+    error::{ApiError, ApiResult},
+    query::Query,
+};
+
+const DEFAULT_PER_PAGE: u32 = 20;
+const MAX_PER_PAGE: u32 = 80;
+
+/// Shop catalogue ordering, mapped onto the upstream `sort` filter.
+#[derive(Clone, Copy)]
+pub enum ShopSort {
+    Newest,
+    PriceDesc,
+    PriceAsc,
+    MostReviewed,
+    MostSold,
+}
+
+impl ShopSort {
+    /// The first entry is the default
+    const ALL: [ShopSort; 5] = [
+        ShopSort::Newest,
+        ShopSort::PriceDesc,
+        ShopSort::PriceAsc,
+        ShopSort::MostReviewed,
+        ShopSort::MostSold,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShopSort::Newest => "newest",
+            ShopSort::PriceDesc => "price_desc",
+            ShopSort::PriceAsc => "price_asc",
+            ShopSort::MostReviewed => "most_reviewed",
+            ShopSort::MostSold => "most_sold",
+        }
+    }
+
+    pub fn code(&self) -> u8 {
+        match self {
+            ShopSort::Newest => 1,
+            ShopSort::PriceDesc => 2,
+            ShopSort::PriceAsc => 3,
+            ShopSort::MostReviewed => 5,
+            ShopSort::MostSold => 8,
+        }
+    }
+}
+
+/// Shop catalogue parameters taken from the request query string.
+pub struct ShopProductsOptions {
+    pub page: u32,
+    pub per_page: u32,
+    pub sort: ShopSort,
+    /// Showcase ID, as listed by `/shop/{domain}/etalase`
+    pub etalase: Option<String>,
+}
+
+impl ShopProductsOptions {
+    pub fn from_query(query: &Query) -> ApiResult<Self> {
+        let (page, per_page) = query.page_and_size("perPage", DEFAULT_PER_PAGE, MAX_PER_PAGE)?;
+
+        let etalase = query.get("etalase");
+
+        if let Some(etalase) = etalase {
+            if !etalase
+                .chars()
+                .all(|v| v.is_ascii_alphanumeric() || v == '_' || v == '-')
+            {
+                return Err(ApiError::BadRequest(
+                    "`etalase` must be a showcase ID".to_string(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            page,
+            per_page,
+            sort: query.choice("sort", &ShopSort::ALL, ShopSort::name)?,
+            etalase: etalase.map(|v| v.to_string()),
+        })
+    }
+
+    /// The following page, if `total_data` has more products past this one.
+    pub fn next_page(&self, total_data: u64) -> Option<u32> {
+        let end = self.page as u64 * self.per_page as u64;
+
+        (end < total_data).then_some(self.page + 1)
+    }
+}
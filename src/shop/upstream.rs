@@ -0,0 +1,224 @@
+use serde::Deserialize;
+
+use crate::de;
+
+#[derive(Deserialize)]
+pub struct ShopInfoCore {
+    #[serde(rename = "shopInfoByID")]
+    pub shop_info_by_id: ShopInfoById,
+}
+
+#[derive(Deserialize)]
+pub struct ShopInfoById {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub result: Vec<ShopInfo>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopInfo {
+    pub shop_core: ShopCore,
+    pub create_info: CreateInfo,
+    #[serde(deserialize_with = "de::number")]
+    pub active_product: u64,
+    pub shop_assets: ShopAssets,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub location: String,
+    pub shipping_loc: ShippingLoc,
+    pub shop_stats: ShopStats,
+    pub status_info: StatusInfo,
+    pub closed_info: ClosedInfo,
+    #[serde(rename = "goldOS")]
+    pub gold_os: GoldOs,
+}
+
+impl ShopInfo {
+    /// Shipping origin city, falling back to the free-form location.
+    pub fn city(&self) -> String {
+        match self.shipping_loc.city_name.is_empty() {
+            true => self.location.clone(),
+            false => self.shipping_loc.city_name.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopCore {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub description: String,
+    pub domain: String,
+    #[serde(rename = "shopID", deserialize_with = "de::string")]
+    pub shop_id: String,
+    pub name: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub tag_line: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInfo {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub open_since: String,
+}
+
+#[derive(Deserialize)]
+pub struct ShopAssets {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub avatar: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub cover: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShippingLoc {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub district_name: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub city_name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopStats {
+    #[serde(deserialize_with = "de::number")]
+    pub product_sold: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub total_tx_success: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub total_showcase: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusInfo {
+    /// `1` when the shop is open
+    #[serde(deserialize_with = "de::number")]
+    pub shop_status: u8,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub status_message: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedInfo {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub closed_note: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub until: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub reason: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoldOs {
+    #[serde(deserialize_with = "de::flag")]
+    pub is_gold: bool,
+    #[serde(deserialize_with = "de::flag")]
+    pub is_official: bool,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub badge: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopStatisticQuery {
+    pub shop_rating: Option<ShopRating>,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub shop_reputation: Vec<ShopReputation>,
+    pub shop_speed: Option<ShopSpeed>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopRating {
+    #[serde(deserialize_with = "de::number")]
+    pub total_rating: u64,
+    #[serde(deserialize_with = "de::number")]
+    pub rating_score: f64,
+}
+
+#[derive(Deserialize)]
+pub struct ShopReputation {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub badge: String,
+    #[serde(deserialize_with = "de::string")]
+    pub score: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShopSpeed {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub message_response_time: String,
+    #[serde(deserialize_with = "de::number")]
+    pub hour: f64,
+}
+
+#[derive(Deserialize)]
+pub struct ShopProducts {
+    #[serde(rename = "GetShopProduct")]
+    pub get_shop_product: GetShopProduct,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetShopProduct {
+    #[serde(deserialize_with = "de::number")]
+    pub total_data: u64,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub data: Vec<ShopProduct>,
+}
+
+#[derive(Deserialize)]
+pub struct ShopProduct {
+    pub name: String,
+    pub product_url: String,
+    pub price: ShopProductPrice,
+    pub primary_image: PrimaryImage,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub category: ShopProductCategory,
+}
+
+#[derive(Deserialize)]
+pub struct ShopProductPrice {
+    pub text_idr: String,
+}
+
+#[derive(Deserialize)]
+pub struct PrimaryImage {
+    pub resize300: String,
+}
+
+#[derive(Deserialize, Default)]
+pub struct ShopProductCategory {
+    #[serde(default, deserialize_with = "de::string")]
+    pub id: String,
+}
+
+#[derive(Deserialize)]
+pub struct ShopShowcasesByShopId {
+    #[serde(rename = "shopShowcasesByShopID")]
+    pub shop_showcases_by_shop_id: ShopShowcases,
+}
+
+#[derive(Deserialize)]
+pub struct ShopShowcases {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub result: Vec<Showcase>,
+}
+
+#[derive(Deserialize)]
+pub struct Showcase {
+    #[serde(deserialize_with = "de::string")]
+    pub id: String,
+    pub name: String,
+    #[serde(deserialize_with = "de::number")]
+    pub count: u64,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub alias: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub uri: String,
+}
@@ -0,0 +1,96 @@
+mod model;
+mod upstream;
+
+use serde_json::json;
+use url::form_urlencoded;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    gql::Operation,
+};
+
+pub use model::SuggestResponse;
+use model::{CategorySuggestion, KeywordSuggestion, ShopSuggestion};
+use upstream::AutoComplete;
+
+const MAX_PREFIX_LENGTH: usize = 100;
+
+const OPERATION: Operation = Operation {
+    name: "AutoComplete",
+    query: include_str!("query.graphql"),
+    akamai: None,
+};
+
+pub async fn suggest(prefix: &str) -> ApiResult<SuggestResponse> {
+    let prefix = prefix.trim();
+
+    if prefix.is_empty() {
+        return Err(ApiError::BadRequest(
+            "Suggestion prefix must not be empty".to_string(),
+        ));
+    }
+
+    if prefix.chars().count() > MAX_PREFIX_LENGTH {
+        return Err(ApiError::BadRequest(format!(
+            "Suggestion prefix must be at most {MAX_PREFIX_LENGTH} characters"
+        )));
+    }
+
+    let param = form_urlencoded::Serializer::new(String::new())
+        .append_pair("q", prefix)
+        .append_pair("source", "search")
+        .append_pair("device", "desktop")
+        .finish();
+
+    let response = OPERATION.fetch(json!({ "param": param })).await?;
+
+    let sections = OPERATION
+        .parse::<AutoComplete>(&response)?
+        .universe_suggestion
+        .data;
+
+    let mut keywords = Vec::new();
+    let mut categories = Vec::new();
+    let mut shops = Vec::new();
+
+    for section in sections {
+        for item in section.items {
+            if item.title.is_empty() {
+                continue;
+            }
+
+            // Items carry their own type, older payloads only tag the section
+            let kind = match item.kind.is_empty() {
+                true => section.id.as_str(),
+                false => item.kind.as_str(),
+            };
+
+            match kind {
+                "keyword" | "autocomplete" => keywords.push(KeywordSuggestion {
+                    keyword: item.title,
+                    url: item.url,
+                }),
+                "category" | "in_category" => categories.push(CategorySuggestion {
+                    name: item.title,
+                    parent: item.subtitle,
+                    url: item.url,
+                }),
+                "shop" => shops.push(ShopSuggestion {
+                    name: item.title,
+                    location: item.subtitle,
+                    url: item.url,
+                    avatar: item.image_url,
+                }),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(SuggestResponse {
+        success: true,
+        prefix: prefix.to_string(),
+        keywords,
+        categories,
+        shops,
+    })
+}
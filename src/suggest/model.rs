@@ -0,0 +1,36 @@
+use serde::Serialize;
+
+/// Body of `GET /suggest/{prefix}`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestResponse {
+    pub success: bool,
+    pub prefix: String,
+    pub keywords: Vec<KeywordSuggestion>,
+    pub categories: Vec<CategorySuggestion>,
+    pub shops: Vec<ShopSuggestion>,
+}
+
+#[derive(Serialize)]
+pub struct KeywordSuggestion {
+    pub keyword: String,
+    pub url: String,
+}
+
+#[derive(Serialize)]
+pub struct CategorySuggestion {
+    pub name: String,
+    /// Parent category, e.g. `Handphone & Tablet`, empty when the upstream
+    /// does not send one
+    pub parent: String,
+    pub url: String,
+}
+
+#[derive(Serialize)]
+pub struct ShopSuggestion {
+    pub name: String,
+    /// Shop city as shown by the upstream
+    pub location: String,
+    pub url: String,
+    pub avatar: String,
+}
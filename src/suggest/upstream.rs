@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+use crate::de;
+
+#[derive(Deserialize)]
+pub struct AutoComplete {
+    pub universe_suggestion: UniverseSuggestion,
+}
+
+#[derive(Deserialize)]
+pub struct UniverseSuggestion {
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub data: Vec<Section>,
+}
+
+/// A group of suggestions, e.g. `autocomplete`, `category` or `shop`.
+#[derive(Deserialize)]
+pub struct Section {
+    #[serde(deserialize_with = "de::string")]
+    pub id: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub items: Vec<Item>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Item {
+    #[serde(rename = "type", default, deserialize_with = "de::nullable")]
+    pub kind: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub url: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub title: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub subtitle: String,
+    #[serde(default, deserialize_with = "de::nullable")]
+    pub image_url: String,
+}